@@ -0,0 +1,73 @@
+//! Counts the heap allocations made while parsing, as a complement to the timings of
+//! `benches/parse.rs`: `cargo bench --bench allocations`
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use datetime_parse::DateTimeFixedOffset;
+
+struct Counting;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: Counting = Counting;
+
+fn allocations(f: impl FnOnce()) -> usize {
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    f();
+    ALLOCATIONS.load(Ordering::Relaxed) - before
+}
+
+fn main() {
+    let dates = include_str!("../examples/dates.txt")
+        .trim()
+        .lines()
+        .collect::<Vec<_>>();
+    let total = allocations(|| {
+        for date in &dates {
+            let _ = date.parse::<DateTimeFixedOffset>();
+        }
+    });
+    println!(
+        "{:<40} {:>6} allocations",
+        "parse examples/dates.txt", total
+    );
+    for date in [
+        "2023-01-05T07:27:19+02:00",
+        "2023-01-05 07:27:19+02:00",
+        "1672903639",
+        "Mon, 6 Jul 1970 15:30:00 PDT",
+        "Wed 1 July 13:19:25.795 +0000 1970",
+        "12/13/2000, 12:12:12",
+    ] {
+        let count = allocations(|| {
+            let _ = date.parse::<DateTimeFixedOffset>();
+        });
+        println!("{:<40} {:>6} allocations", date, count);
+    }
+    let date = "2023-01-05T07:27:19";
+    let count = allocations(|| {
+        let _ = datetime_parse::normalize(date);
+    });
+    println!(
+        "{:<40} {:>6} allocations",
+        format!("normalize {}", date),
+        count
+    );
+}
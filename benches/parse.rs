@@ -0,0 +1,46 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use datetime_parse::DateTimeFixedOffset;
+
+fn parse_examples(c: &mut Criterion) {
+    let dates = include_str!("../examples/dates.txt")
+        .trim()
+        .lines()
+        .collect::<Vec<_>>();
+    c.bench_function("parse examples/dates.txt", |b| {
+        b.iter(|| {
+            for date in &dates {
+                let _ = black_box(date).parse::<DateTimeFixedOffset>();
+            }
+        })
+    });
+    for date in [
+        "2023-01-05T07:27:19+02:00",
+        "1672903639",
+        "Mon, 6 Jul 1970 15:30:00 PDT",
+        "Wed 1 July 13:19:25.795 +0000 1970",
+        "Feb 12 12:00",
+        "not a date",
+    ] {
+        c.bench_function(date, |b| {
+            b.iter(|| black_box(date).parse::<DateTimeFixedOffset>())
+        });
+    }
+}
+
+fn parse_rfc3339(c: &mut Criterion) {
+    // canonical RFC 3339 is read as is, while the same date/time with a space
+    // goes through `standardize_date` and the parser branches before `Rfc3339`
+    let mut group = c.benchmark_group("rfc3339");
+    for (path, date) in [
+        ("fast path", "2023-01-05T07:27:19.123+02:00"),
+        ("slow path", "2023-01-05 07:27:19.123+02:00"),
+    ] {
+        group.bench_function(path, |b| {
+            b.iter(|| black_box(date).parse::<DateTimeFixedOffset>())
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, parse_examples, parse_rfc3339);
+criterion_main!(benches);
@@ -0,0 +1,104 @@
+//! ISO 8601 durations
+//!
+//! The duration of an interval such as `2023-01-05T00:00:00Z/P1DT2H`.
+//! Years and months are calendar months, so `P1M` after January 31st is the
+//! last day of February, while the other components are exact lengths of time.
+use alloc::vec::Vec;
+
+use chrono::{DateTime, Duration, FixedOffset, Months};
+
+/// A parsed `PnYnMnWnDTnHnMnS` duration, whose months or time is `None` when the
+/// components add up to more than fits, so that applying it fails as out of range
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct IsoDuration {
+    months: Option<u32>,
+    time: Option<Duration>,
+}
+
+impl IsoDuration {
+    /// Returns the instant this duration after `dt`
+    pub(crate) fn after(&self, dt: DateTime<FixedOffset>) -> Option<DateTime<FixedOffset>> {
+        dt.checked_add_months(Months::new(self.months?))?
+            .checked_add_signed(self.time?)
+    }
+
+    /// Returns the instant this duration before `dt`
+    pub(crate) fn before(&self, dt: DateTime<FixedOffset>) -> Option<DateTime<FixedOffset>> {
+        dt.checked_sub_signed(self.time?)?
+            .checked_sub_months(Months::new(self.months?))
+    }
+
+    fn add_months(&mut self, months: Option<u32>) {
+        self.months = self.months.zip(months).and_then(|(a, b)| a.checked_add(b));
+    }
+
+    fn add_time(&mut self, time: Option<Duration>) {
+        self.time = self.time.zip(time).and_then(|(a, b)| a.checked_add(&b));
+    }
+}
+
+/// Parses an ISO 8601 duration, eg: `P1D`, `PT1H30M`, `P1Y2M10DT2H30M`, `P2W` or `PT0.5S`.
+/// Only the seconds may have a fraction (with a `.` or a `,`)
+pub(crate) fn parse(s: &str) -> Option<IsoDuration> {
+    let rest = s.strip_prefix('P')?;
+    let (date, time) = match rest.split_once('T') {
+        Some((_, "")) => return None,
+        Some((date, time)) => (date, time),
+        None => (rest, ""),
+    };
+    if date.is_empty() && time.is_empty() {
+        return None;
+    }
+    let mut duration = IsoDuration {
+        months: Some(0),
+        time: Some(Duration::zero()),
+    };
+    for (value, unit) in components(date)? {
+        let value = value.parse::<u32>().ok()?;
+        match unit {
+            'Y' => duration.add_months(value.checked_mul(12)),
+            'M' => duration.add_months(Some(value)),
+            'W' => duration.add_time(Duration::try_weeks(value.into())),
+            'D' => duration.add_time(Duration::try_days(value.into())),
+            _ => return None,
+        }
+    }
+    for (value, unit) in components(time)? {
+        let time = match unit {
+            'H' => Duration::try_hours(value.parse().ok()?),
+            'M' => Duration::try_minutes(value.parse().ok()?),
+            'S' => {
+                let (secs, fraction) = value.split_once(['.', ',']).unwrap_or((value, ""));
+                // digits past the nanoseconds are truncated
+                let fraction = fraction.get(..9).unwrap_or(fraction);
+                let nanos = match fraction {
+                    "" => 0,
+                    _ => fraction.parse::<i64>().ok()? * 10_i64.pow(9 - fraction.len() as u32),
+                };
+                Duration::try_seconds(secs.parse().ok()?)
+                    .and_then(|secs| secs.checked_add(&Duration::nanoseconds(nanos)))
+            }
+            _ => return None,
+        };
+        duration.add_time(time);
+    }
+    Some(duration)
+}
+
+/// Splits `1Y2M10D` into `[("1", 'Y'), ("2", 'M'), ("10", 'D')]`
+fn components(s: &str) -> Option<Vec<(&str, char)>> {
+    let mut components = Vec::new();
+    let mut start = 0;
+    for (idx, c) in s.char_indices() {
+        if c.is_ascii_digit() || c == '.' || c == ',' {
+            continue;
+        }
+        let value = &s[start..idx];
+        if !value.starts_with(|c: char| c.is_ascii_digit()) {
+            return None;
+        }
+        components.push((value, c));
+        start = idx + c.len_utf8();
+    }
+    (start == s.len()).then_some(components)
+}
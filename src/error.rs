@@ -0,0 +1,74 @@
+//! Errors
+use alloc::{string::String, vec::Vec};
+use core::fmt;
+
+use crate::{DateTimeFixedOffset, ParsedVia};
+
+/// ParseError is returned when a date/time string cannot be parsed
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseError {
+    /// The input was empty
+    Empty,
+    /// None of the known formats matched the input
+    Unrecognized(String),
+    /// The input matched, but through a branch scoring below `ParserOptions.min_confidence`
+    LowConfidence { via: ParsedVia, confidence: f32 },
+    /// The input has a known shape but one of its components is impossible,
+    /// eg: `month` 13 or `day` 30 in February, or the `date` falls outside of
+    /// `ParserOptions.min` and `ParserOptions.max`
+    OutOfRange {
+        component: &'static str,
+        value: String,
+    },
+    /// The input could not be read, eg: a line of `Parser::parse_lines`
+    Io(String),
+    /// The end of a range precedes its start, see `ParserOptions.reject_reversed_range`
+    ReversedRange(String),
+    /// The input is a local time that occurs twice, see `ParserOptions.ambiguous`
+    AmbiguousLocalTime(String),
+    /// The input reads as more than one date, eg: `01-02-03` as January 2nd or February 1st,
+    /// see `ParserOptions.strict_ambiguity`
+    Ambiguous {
+        candidates: Vec<DateTimeFixedOffset>,
+    },
+    /// The input has more bytes or whitespace separated tokens than allowed by
+    /// `ParserOptions.max_input_len` and `ParserOptions.max_tokens`
+    TooLong { unit: &'static str, max: usize },
+    /// The input has a numeric offset followed by a UTC zone that disagrees with it,
+    /// eg: `2023-01-05T07:27:19+02:00Z`
+    ConflictingZone(String),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::Empty => write!(f, "cannot be empty"),
+            ParseError::Unrecognized(e) => write!(f, "{}", e),
+            ParseError::LowConfidence { via, confidence } => write!(
+                f,
+                "matched as {:?} with a low confidence of {}",
+                via, confidence
+            ),
+            ParseError::OutOfRange { component, value } => {
+                write!(f, "{} out of range: {}", component, value)
+            }
+            ParseError::Io(e) => write!(f, "{}", e),
+            ParseError::ReversedRange(range) => write!(f, "range ends before it starts: {}", range),
+            ParseError::AmbiguousLocalTime(s) => {
+                write!(f, "local time occurs twice in the timezone: {}", s)
+            }
+            ParseError::Ambiguous { candidates } => {
+                write!(f, "ambiguous date, could be any of:")?;
+                for candidate in candidates {
+                    write!(f, " {}", candidate)?;
+                }
+                Ok(())
+            }
+            ParseError::TooLong { unit, max } => write!(f, "input has more than {} {}", max, unit),
+            ParseError::ConflictingZone(s) => write!(f, "offset contradicts the UTC zone: {}", s),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseError {}
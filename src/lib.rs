@@ -2,14 +2,45 @@
 //! and normalising to a standard fixed offset format (rfc3339).
 //! Parsed date will be returned `DateTime<FixedOffset>`
 //!
+//! Fractional seconds are kept to the nanosecond, whatever the number of digits:
+//! digits past the ninth are truncated, not rounded, eg: `07:27:19.1234567899` is
+//! `07:27:19.123456789`. A decimal unix timestamp is truncated to its unit.
+//!
+//! Without the default `std` feature the crate is `no_std` (it still needs `alloc`)
+//! and there is no system clock or timezone, see `ParserOptions.now`.
+#![cfg_attr(all(not(feature = "std"), not(test)), no_std)]
+
+extern crate alloc;
+
+use alloc::{
+    borrow::Cow,
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
 
 use chrono::{
-    DateTime, Datelike, FixedOffset, Local, NaiveDate, NaiveDateTime, NaiveTime, ParseError,
-    TimeZone,
+    DateTime, Datelike, Duration, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc,
+    Weekday,
 };
 
-#[cfg(test)]
+mod duration;
+mod error;
+#[cfg(feature = "locale")]
+mod locale;
+mod parser;
+mod range;
+#[cfg(all(test, feature = "std"))]
 mod tests;
+#[cfg(all(test, not(feature = "std")))]
+mod tests_no_std;
+mod trace;
+mod tz;
+
+pub use error::ParseError;
+#[cfg(feature = "locale")]
+pub use locale::Locale;
+pub use parser::{Ambiguous, DateOrder, ExcelEpoch, Parser, ParserOptions, WeekNumbering};
 
 type Error = String;
 
@@ -28,174 +59,1804 @@ type Error = String;
 ///     Err(e) => println!("Error: {}", e)
 /// }
 /// ```
-#[derive(Debug)]
+///
+/// Comparisons follow chrono: two values are equal when they are the same instant,
+/// whatever their offsets.
+///
+/// It displays as RFC 3339, except that an offset with seconds (local mean time,
+/// eg: `+00:06:42`) keeps them, so that the output parses back to the same instant.
+/// chrono's `to_rfc3339` rounds such an offset to the minute, eg: to `+00:07`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct DateTimeFixedOffset(pub DateTime<FixedOffset>);
 
-impl std::str::FromStr for DateTimeFixedOffset {
-    type Err = Error;
+impl core::fmt::Display for DateTimeFixedOffset {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if self.0.offset().local_minus_utc() % 60 != 0 {
+            return write!(f, "{}", self.0.format("%Y-%m-%dT%H:%M:%S%.f%::z"));
+        }
+        write!(f, "{}", self.0.to_rfc3339())
+    }
+}
+
+impl From<DateTime<FixedOffset>> for DateTimeFixedOffset {
+    fn from(dt: DateTime<FixedOffset>) -> Self {
+        DateTimeFixedOffset(dt)
+    }
+}
+
+impl From<DateTimeFixedOffset> for DateTime<FixedOffset> {
+    fn from(dt: DateTimeFixedOffset) -> Self {
+        dt.0
+    }
+}
+
+/// Converts to the same instant in UTC
+impl From<DateTimeFixedOffset> for DateTime<Utc> {
+    fn from(dt: DateTimeFixedOffset) -> Self {
+        dt.0.with_timezone(&Utc)
+    }
+}
+
+/// Converts to a `time::OffsetDateTime` with the same offset and nanoseconds.
+/// Fails with `ParseError::OutOfRange` outside of the years `time` supports (±9999)
+/// and on a leap second, which `time` cannot represent
+#[cfg(feature = "time")]
+impl TryFrom<DateTimeFixedOffset> for time::OffsetDateTime {
+    type Error = ParseError;
+
+    fn try_from(dt: DateTimeFixedOffset) -> Result<Self, Self::Error> {
+        let out_of_range = |component| ParseError::OutOfRange {
+            component,
+            value: dt.to_string(),
+        };
+        let offset = time::UtcOffset::from_whole_seconds(dt.0.offset().local_minus_utc())
+            .map_err(|_| out_of_range("offset"))?;
+        time::OffsetDateTime::from_unix_timestamp(dt.0.timestamp())
+            .map_err(|_| out_of_range("timestamp"))?
+            .replace_nanosecond(dt.0.timestamp_subsec_nanos())
+            .map_err(|_| out_of_range("second"))?
+            .checked_to_offset(offset)
+            .ok_or_else(|| out_of_range("timestamp"))
+    }
+}
+
+impl core::str::FromStr for DateTimeFixedOffset {
+    type Err = ParseError;
 
-    fn from_str(s: &str) -> Result<Self, Error> {
-        parse_from(s).map(DateTimeFixedOffset)
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        parse_from(s, &ParserOptions::default()).map(|(dt, _)| DateTimeFixedOffset(dt))
     }
 }
 
+/// parse_to_time parses the date/time string like `str::parse::<DateTimeFixedOffset>()`
+/// into a `time::OffsetDateTime`
+///
+/// ## Example usage:
+/// ```
+/// let dt = datetime_parse::parse_to_time("2023-01-05T07:27:19.5+02:00").unwrap();
+/// assert_eq!(dt.offset().whole_hours(), 2);
+/// assert_eq!(dt.millisecond(), 500);
+/// ```
+#[cfg(feature = "time")]
+pub fn parse_to_time(s: &str) -> Result<time::OffsetDateTime, ParseError> {
+    s.parse::<DateTimeFixedOffset>()?.try_into()
+}
+
+/// parse_with_source parses the date/time string like `str::parse::<DateTimeFixedOffset>()`
+/// and also returns the parser branch that matched, which helps when debugging
+/// how an input was interpreted.
+///
+/// ## Example usage:
+/// ```
+/// use datetime_parse::{parse_with_source, ParsedVia};
+///
+/// let (parsed, via) = parse_with_source("1672903639").unwrap();
+/// assert_eq!(via, ParsedVia::UnixTimestamp);
+/// assert_eq!(parsed.to_rfc3339(), "2023-01-05T07:27:19+00:00");
+/// ```
+pub fn parse_with_source(s: &str) -> Result<(DateTime<FixedOffset>, ParsedVia), ParseError> {
+    parse_from(s, &ParserOptions::default())
+}
+
+/// parse_with_origin parses the date/time string like `str::parse::<DateTimeFixedOffset>()`
+/// and also tells whether the offset of the result was parsed from the input, inferred
+/// from the local timezone or is UTC by default (unix timestamps), so that an inferred
+/// offset can be trusted less. With `ParserOptions.output_timezone` set, it tells where
+/// the offset converted from comes from.
+///
+/// ## Example usage:
+/// ```
+/// use datetime_parse::{parse_with_origin, OffsetOrigin};
+///
+/// let (_, origin) = parse_with_origin("2023-01-05T07:27:19+02:00").unwrap();
+/// assert_eq!(origin, OffsetOrigin::Parsed);
+/// // the local timezone needs the `std` feature
+/// # #[cfg(feature = "std")] {
+/// let (_, origin) = parse_with_origin("2023-01-05 07:27:19").unwrap();
+/// assert_eq!(origin, OffsetOrigin::Inferred);
+/// # }
+/// ```
+pub fn parse_with_origin(s: &str) -> Result<(DateTime<FixedOffset>, OffsetOrigin), ParseError> {
+    Parser::new()
+        .parse_with_origin(s)
+        .map(|(dt, origin)| (dt.0, origin))
+}
+
+/// parse_best_effort parses the date/time string like `str::parse::<DateTimeFixedOffset>()`
+/// and also lists what was guessed along the way: a year filled in, an offset taken from
+/// the local timezone, an ambiguous timezone abbreviation or a truncated precision.
+/// An empty list means the input was read as is, eg: to flag the rows of a data set
+/// that need a second look.
+///
+/// ## Example usage:
+/// ```
+/// use datetime_parse::{parse_best_effort, Warning};
+///
+/// let (_, warnings) = parse_best_effort("2023-01-05T07:27:19Z").unwrap();
+/// assert!(warnings.is_empty());
+/// let (dt, warnings) = parse_best_effort("2023-01-05 07:27:19 CST").unwrap();
+/// assert_eq!(dt.0.to_rfc3339(), "2023-01-05T07:27:19-06:00");
+/// assert!(matches!(warnings[0], Warning::AmbiguousAbbreviation { .. }));
+/// ```
+pub fn parse_best_effort(s: &str) -> Result<(DateTimeFixedOffset, Vec<Warning>), ParseError> {
+    Parser::new().parse_best_effort(s)
+}
+
+/// parse_with_formats parses the date/time string with the given chrono format strings only,
+/// skipping the guessing of the default pipeline. Each format is tried in order, first for
+/// a date/time with an offset, then for a naive date/time and last for a naive date.
+/// Naive results are read in the local timezone, as inputs without an offset are by
+/// `str::parse::<DateTimeFixedOffset>()`, and a naive date is taken at midnight.
+///
+/// ## Example usage:
+/// ```
+/// use datetime_parse::parse_with_formats;
+///
+/// // the default pipeline reads this as May 1st
+/// let dt = parse_with_formats("05/01/2023 07:27:19 +0200", &["%d/%m/%Y %T %z"]).unwrap();
+/// assert_eq!(dt.to_rfc3339(), "2023-01-05T07:27:19+02:00");
+/// ```
+pub fn parse_with_formats(s: &str, formats: &[&str]) -> Result<DateTime<FixedOffset>, ParseError> {
+    Parser::new().parse_with_formats(s, formats).map(|dt| dt.0)
+}
+
+/// Tries the given formats on the input as is, see `parse_with_formats`
+fn parse_with_formats_from(
+    s: &str,
+    formats: &[&str],
+    options: &ParserOptions,
+) -> Result<DateTime<FixedOffset>, ParseError> {
+    let s = s.trim();
+    if s.is_empty() {
+        return Err(ParseError::Empty);
+    }
+    try_formats(formats, |f| DateTime::parse_from_str(s, f))
+        .or_else(|_| {
+            try_formats(formats, |f| NaiveDateTime::parse_from_str(s, f))
+                .and_then(|x| options.local(&x))
+        })
+        .or_else(|_| {
+            try_formats(formats, |f| NaiveDate::parse_from_str(s, f))
+                .and_then(|x| options.start_of_day(x))
+        })
+        .map(|dt| match options.output_timezone {
+            Some(tz) => dt.with_timezone(&tz),
+            None => dt,
+        })
+        .map_err(ParseError::Unrecognized)
+        .and_then(|dt| options.within_bounds(dt))
+}
+
+/// parse_in_offset parses the date/time string like `str::parse::<DateTimeFixedOffset>()`
+/// and expresses the result in the given offset, keeping the instant, as
+/// `ParserOptions.output_timezone` does. Inputs without an offset are still read in the
+/// local timezone, unlike with `ParserOptions.local_offset`.
+///
+/// ## Example usage:
+/// ```
+/// use chrono::FixedOffset;
+///
+/// let ist = FixedOffset::east_opt(5 * 3600 + 1800).unwrap();
+/// let dt = datetime_parse::parse_in_offset("2023-01-05T07:27:19Z", ist).unwrap();
+/// assert_eq!(dt.to_rfc3339(), "2023-01-05T12:57:19+05:30");
+/// ```
+pub fn parse_in_offset(s: &str, offset: FixedOffset) -> Result<DateTime<FixedOffset>, ParseError> {
+    Parser::new()
+        .output_timezone(offset)
+        .parse(s)
+        .map(|dt| dt.0)
+}
+
+/// parse_range parses both ends of a date range such as `2023-01-05 to 2023-01-10`,
+/// split on `to`, `until`, `..` or an en dash (`–`). A plain `-` separates the ends only
+/// when both sides parse on their own, eg: `2023-01-05 - 2023-01-10`
+///
+/// ## Example usage:
+/// ```
+/// let (start, end) =
+///     datetime_parse::parse_range("Jan 5 2023 00:00 UTC – Jan 10 2023 00:00 UTC").unwrap();
+/// assert_eq!((end.0 - start.0).num_days(), 5);
+/// ```
+pub fn parse_range(s: &str) -> Result<(DateTimeFixedOffset, DateTimeFixedOffset), ParseError> {
+    Parser::new().parse_range(s)
+}
+
+/// parse_interval parses both ends of an ISO 8601 interval: a start and an end,
+/// a start and a duration, or a duration and an end, separated by `/`
+/// eg: `2023-01-05T00:00:00Z/2023-01-06T00:00:00Z`, `2023-01-05T00:00:00Z/P1DT2H`
+/// or `P1D/2023-01-06T00:00:00Z`. Each end is parsed like `str::parse::<DateTimeFixedOffset>()`
+/// and years and months of a duration are calendar ones, eg: `2023-01-31/P1M` ends on February 28th
+///
+/// ## Example usage:
+/// ```
+/// let (start, end) = datetime_parse::parse_interval("2023-01-05T00:00:00Z/P1DT2H").unwrap();
+/// assert_eq!(end.0.to_rfc3339(), "2023-01-06T02:00:00+00:00");
+/// assert_eq!((end.0 - start.0).num_hours(), 26);
+/// ```
+pub fn parse_interval(s: &str) -> Result<(DateTimeFixedOffset, DateTimeFixedOffset), ParseError> {
+    Parser::new().parse_interval(s)
+}
+
+/// parse_components parses a date, time and timezone that were already split apart,
+/// eg: the fields of a structured log, without looking for them in a whole string.
+/// The date is required, an empty one is `ParseError::Empty`.
+/// A timezone, eg: `PST`, `GMT+2` or `-0800`, is combined with the date and time
+/// (midnight when there is none), otherwise they are parsed like
+/// `str::parse::<DateTimeFixedOffset>()` would parse `"{date} {time}"`
+///
+/// ## Example usage:
+/// ```
+/// let dt = datetime_parse::parse_components("2023-01-05", Some("07:27:19"), Some("PST"));
+/// assert_eq!(dt.unwrap().to_rfc3339(), "2023-01-05T07:27:19-08:00");
+/// ```
+pub fn parse_components(
+    date: &str,
+    time: Option<&str>,
+    tz: Option<&str>,
+) -> Result<DateTime<FixedOffset>, ParseError> {
+    Parser::new().parse_components(date, time, tz)
+}
+
+/// parse_date_only parses the date/time string like `str::parse::<DateTimeFixedOffset>()`
+/// and keeps only its calendar date, taken in the offset that was parsed (or assumed)
+/// rather than in UTC, so `2023-01-05T02:00:00+05:00` is January 5th
+///
+/// ## Example usage:
+/// ```
+/// let date = datetime_parse::parse_date_only("2023-01-05T02:00:00+05:00").unwrap();
+/// assert_eq!(date.to_string(), "2023-01-05");
+/// ```
+pub fn parse_date_only(s: &str) -> Result<NaiveDate, ParseError> {
+    Parser::new().parse_date_only(s)
+}
+
+/// parse_time_only parses the date/time string like `str::parse::<DateTimeFixedOffset>()`
+/// and keeps only its time of day, in the offset that was parsed (or assumed).
+/// A date without a time gives midnight.
+///
+/// ## Example usage:
+/// ```
+/// let time = datetime_parse::parse_time_only("2023-01-05T07:27:19Z").unwrap();
+/// assert_eq!(time.to_string(), "07:27:19");
+/// ```
+pub fn parse_time_only(s: &str) -> Result<NaiveTime, ParseError> {
+    Parser::new().parse_time_only(s)
+}
+
+/// parse_naive parses the date/time string into its wall-clock date and time, without
+/// assuming any timezone for an input without one, so a local time that the system
+/// timezone skips or repeats (DST) is returned as written. An input with an offset
+/// gives its wall-clock time in that offset, eg: `07:27:19+02:00` is `07:27:19`,
+/// and a unix timestamp its wall-clock time in UTC.
+///
+/// ## Example usage:
+/// ```
+/// let dt = datetime_parse::parse_naive("2023-03-26 02:30:00").unwrap();
+/// assert_eq!(dt.to_string(), "2023-03-26 02:30:00");
+/// let dt = datetime_parse::parse_naive("2023-01-05T07:27:19+02:00").unwrap();
+/// assert_eq!(dt.to_string(), "2023-01-05 07:27:19");
+/// ```
+pub fn parse_naive(s: &str) -> Result<NaiveDateTime, ParseError> {
+    Parser::new().parse_naive(s)
+}
+
+/// is_valid checks whether the date/time string parses like
+/// `str::parse::<DateTimeFixedOffset>()` would, eg: to validate a form field
+///
+/// ## Example usage:
+/// ```
+/// assert!(datetime_parse::is_valid("2023-01-05T07:27:19Z"));
+/// assert!(!datetime_parse::is_valid("2023-02-30"));
+/// ```
+pub fn is_valid(s: &str) -> bool {
+    Parser::new().is_valid(s)
+}
+
+/// parse_or parses the date/time string like `str::parse::<DateTimeFixedOffset>()`,
+/// returning `default` when it cannot be parsed. The error is discarded,
+/// use `str::parse` where the reason matters.
+///
+/// ## Example usage:
+/// ```
+/// use chrono::DateTime;
+///
+/// let epoch = DateTime::parse_from_rfc3339("1970-01-01T00:00:00Z").unwrap();
+/// let dt = datetime_parse::parse_or("not a date", epoch);
+/// assert_eq!(dt, epoch);
+/// ```
+pub fn parse_or(s: &str, default: DateTime<FixedOffset>) -> DateTime<FixedOffset> {
+    Parser::new().parse_or(s, default)
+}
+
+/// parse_or_else parses the date/time string like `str::parse::<DateTimeFixedOffset>()`,
+/// returning the result of `f` when it cannot be parsed. As with `parse_or`,
+/// the error is discarded.
+///
+/// ## Example usage:
+/// ```
+/// use chrono::DateTime;
+///
+/// let dt = datetime_parse::parse_or_else("not a date", || {
+///     DateTime::parse_from_rfc3339("1970-01-01T00:00:00Z").unwrap()
+/// });
+/// assert_eq!(dt.timestamp(), 0);
+/// ```
+pub fn parse_or_else<F>(s: &str, f: F) -> DateTime<FixedOffset>
+where
+    F: FnOnce() -> DateTime<FixedOffset>,
+{
+    Parser::new().parse_or_else(s, f)
+}
+
+/// parse_many parses every item with the default options, keeping the results in input order
+/// so a failure can be traced back to its position.
+///
+/// ## Example usage:
+/// ```
+/// use datetime_parse::parse_many;
+///
+/// let results = parse_many(["2023-01-05T00:00:00Z", "not a date"]);
+/// assert!(results[0].is_ok());
+/// assert!(results[1].is_err());
+/// ```
+pub fn parse_many<I>(items: I) -> Vec<Result<DateTimeFixedOffset, ParseError>>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    Parser::new().parse_many(items)
+}
+
+/// parse_many_ok parses every item with the default options and returns only the
+/// successes, each with the index of its item in the input
+pub fn parse_many_ok<I>(items: I) -> Vec<(usize, DateTimeFixedOffset)>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    Parser::new().parse_many_ok(items)
+}
+
+/// parse_list splits the string on `sep` before anything else, so a separator that
+/// the parser would otherwise drop (such as `,`) splits the list rather than the dates,
+/// and parses every element with the default options. It returns all the dates, or the
+/// errors of the elements that failed, each with the 0-based index of its element.
+/// Blank elements, eg: after a trailing separator, are skipped.
+///
+/// ## Example usage:
+/// ```
+/// use datetime_parse::parse_list;
+///
+/// let dates = parse_list("2023-01-05T00:00:00Z, 2023-01-06T00:00:00Z", ",").unwrap();
+/// assert_eq!(dates.len(), 2);
+/// let errors = parse_list("2023-01-05T00:00:00Z\nnot a date\n", "\n").unwrap_err();
+/// assert_eq!(errors.len(), 1);
+/// assert_eq!(errors[0].0, 1);
+/// ```
+pub fn parse_list(
+    s: &str,
+    sep: &str,
+) -> Result<Vec<DateTimeFixedOffset>, Vec<(usize, ParseError)>> {
+    Parser::new().parse_list(s, sep)
+}
+
+/// parse_lines lazily parses one date/time per line with the default options,
+/// skipping blank lines and yielding each result with its 1-based line number
+#[cfg(feature = "std")]
+pub fn parse_lines<R: std::io::BufRead>(
+    reader: R,
+) -> impl Iterator<Item = (usize, Result<DateTimeFixedOffset, ParseError>)> {
+    Parser::new().parse_lines(reader)
+}
+
+/// parse_verbose parses the date/time string like `str::parse::<DateTimeFixedOffset>()`,
+/// and on failure returns every format pattern and parser branch that was tried,
+/// each with the error it failed with
+///
+/// ## Example usage:
+/// ```
+/// let attempts = datetime_parse::parse_verbose("2023-01-05 7:27 later").unwrap_err();
+/// for (pattern, error) in &attempts {
+///     println!("{}: {}", pattern, error);
+/// }
+/// assert!(attempts.iter().any(|(pattern, _)| pattern == "%Y-%m-%dT%T"));
+/// ```
+#[cfg(feature = "std")]
+pub fn parse_verbose(s: &str) -> Result<DateTimeFixedOffset, Vec<(String, String)>> {
+    Parser::new().parse_verbose(s)
+}
+
+/// has_explicit_timezone checks whether the date/time string carries its own offset or zone,
+/// eg: `Z`, `+02:00`, `-0500`, `PST`, `GMT+5` or `Pacific Standard Time`, without parsing it.
+/// When it does not, the parsed result is in the local timezone (or `ParserOptions.local_offset`).
+/// Unix timestamps and .NET dates are not considered, as they carry no zone in their text.
+/// A pair of quotes or brackets around the input is ignored, as it is when parsing.
+///
+/// ## Example usage:
+/// ```
+/// use datetime_parse::has_explicit_timezone;
+///
+/// assert!(has_explicit_timezone("2023-01-05T07:27:19+02:00"));
+/// assert!(has_explicit_timezone("Mon, 6 Jul 1970 15:30:00 PDT"));
+/// assert!(!has_explicit_timezone("2023-01-05 07:27:19"));
+/// ```
+pub fn has_explicit_timezone(s: &str) -> bool {
+    has_zone(&standardize_date(unwrap_enclosing(s)))
+}
+
+/// Checks a date/time for a trailing `Z`, a numeric offset or a trailing zone,
+/// see `has_explicit_timezone`
+fn has_zone(s: &str) -> bool {
+    if s.ends_with('Z') && s[..s.len() - 1].ends_with(|c: char| c.is_ascii_digit()) {
+        return true;
+    }
+    if has_numeric_offset(s) {
+        return true;
+    }
+    // the zone may come before the year, eg: Wed Jul 1 3:33pm PST 1970
+    let before_year = match s.rsplit_once(' ') {
+        Some((head, year)) if year.len() == 4 && year.chars().all(|c| c.is_ascii_digit()) => head,
+        _ => s,
+    };
+    let trailing_zone = |s| {
+        is_tz_alpha(s)
+            .filter(|(dt, _)| !dt.is_empty())
+            .is_some_and(|(_, tz)| zone_offset(tz).is_some())
+    };
+    trailing_zone(s) || trailing_zone(before_year)
+}
+
+/// Checks for a `±HH`, `±HHMM`, `±HH:MM` or `±HH:MM:SS` offset after the time
+/// eg: 07:27:19+02:00, 15:30:00 -0500 or 12:00:00 +00:06:42
+fn has_numeric_offset(s: &str) -> bool {
+    let Some(time) = s.find(':') else {
+        return false;
+    };
+    s[time..].match_indices(['+', '-']).any(|(idx, _)| {
+        let offset = s[time + idx + 1..].split(' ').next().unwrap_or_default();
+        let digits = offset.replace(':', "");
+        let colons = offset.len() - digits.len();
+        matches!((digits.len(), colons), (2 | 4, 0 | 1) | (6, 2))
+            && digits.chars().all(|c| c.is_ascii_digit())
+    })
+}
+
+/// normalize returns the input as the parsers see it, after separators have been
+/// standardized and extra punctuation or whitespace has been removed
+/// eg: `12/13/2000,  12:12:12` to `12-13-2000 12:12:12`
+pub fn normalize(s: &str) -> String {
+    standardize_date(s).into_owned()
+}
+
+/// ParsedVia identifies the parser branch that matched the input
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ParsedVia {
+    /// eg: 2023 (when `reject_year_only` is disabled)
+    YearOnly,
+    /// eg: 20230105T072719Z, 20230105 or 20230105-072719
+    BasicIso,
+    /// eg: /Date(1672903639123+0200)/ or 638089107190000000 (ticks)
+    DotNet,
+    /// eg: 44927.3107 (when `excel_epoch` is set)
+    ExcelSerial,
+    /// eg: 1430Z, 1430 PST or 1430 (when `compact_time` is enabled)
+    CompactTime,
+    /// eg: 1672903639, 1672903639123, 1672903639123ms or epoch:1672903639
+    UnixTimestamp,
+    /// eg: 2023-01-05T07:27:19+02:00, 2023-01-05T07:27:19+0200 or 2023-01-05T07:27:19Z
+    Rfc3339,
+    /// eg: Mon, 6 Jul 1970 15:30:00 +0200
+    DatetimeWithTz,
+    /// eg: 2006-01-02 15:04:05 -0700 MST
+    GoDatetime,
+    /// eg: Sunday, 06-Nov-94 08:49:37 GMT
+    HttpDate,
+    /// eg: 10/Oct/2000:13:55:36 -0700 or [10/Oct/2000:13:55:36 -0700] (Apache/Nginx access logs)
+    Clf,
+    /// eg: 2023-01-05 07:27:19
+    DatetimeWithoutTz,
+    /// eg: January 2023 or 01 2023 (the 1st of the month)
+    MonthYear,
+    /// eg: Q2 2023, 2023 Q2 or 2023-Q2 (the first day of the quarter)
+    Quarter,
+    /// eg: Week 5 2023, 2023 W5 or 2023-W05 (the Monday of the week),
+    /// 2023-W05-1 or 2023-W05-1T07:27:19Z (ISO week dates)
+    Week,
+    /// eg: 2023年1月5日 or 2023年1月5日 7时27分 (Chinese/Japanese delimiters)
+    Cjk,
+    /// eg: 2023-01-05
+    DateWithoutTz,
+    /// eg: 07:27:19
+    TimeWithoutTz,
+    /// eg: 3:00pm PST or 07:27:19.123+02:00
+    TimeWithTz,
+    /// eg: +1d 09:00
+    DayOffsetTime,
+    /// eg: 1970-12-25 16:16:16 PST
+    YmdHmsTz,
+    /// eg: 1 Jan 1970 22:00:00 PDT
+    DmmmyHmsTz,
+    /// eg: Feb 14 2022 13:13:55 GMT+0000
+    MmmddyyyyHmsTz,
+    /// eg: Wed Jul 1 3:33pm PST 1970
+    DatetimeWithTzBeforeYear,
+    /// eg: Oct 11 22:14:15 or Oct  1 22:14:15 (BSD syslog, the year is filled in)
+    Syslog,
+    /// eg: Feb 12 12:00 (the year is filled in)
+    Others,
+}
+
+impl ParsedVia {
+    /// How confident the branch is that it read the input as intended, from 0.0 to 1.0.
+    /// Formats with an explicit offset score highest; guesses filling in a missing
+    /// year or picking a timestamp unit score lowest.
+    pub fn confidence(&self) -> f32 {
+        match self {
+            ParsedVia::Rfc3339
+            | ParsedVia::DatetimeWithTz
+            | ParsedVia::GoDatetime
+            | ParsedVia::HttpDate
+            | ParsedVia::Clf => 1.0,
+            ParsedVia::DatetimeWithoutTz
+            | ParsedVia::DotNet
+            | ParsedVia::YmdHmsTz
+            | ParsedVia::DmmmyHmsTz
+            | ParsedVia::MmmddyyyyHmsTz => 0.9,
+            ParsedVia::BasicIso
+            | ParsedVia::ExcelSerial
+            | ParsedVia::UnixTimestamp
+            | ParsedVia::Cjk
+            | ParsedVia::DateWithoutTz
+            | ParsedVia::TimeWithoutTz
+            | ParsedVia::TimeWithTz
+            | ParsedVia::DatetimeWithTzBeforeYear => 0.8,
+            ParsedVia::MonthYear
+            | ParsedVia::Quarter
+            | ParsedVia::Week
+            | ParsedVia::DayOffsetTime
+            | ParsedVia::CompactTime
+            | ParsedVia::Syslog => 0.7,
+            ParsedVia::YearOnly | ParsedVia::Others => 0.5,
+        }
+    }
+}
+
+/// OffsetOrigin tells where the offset of a result comes from, see `parse_with_origin`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OffsetOrigin {
+    /// The input carries it, eg: `Z`, `+02:00` or `PST`
+    Parsed,
+    /// The input has none, so it was taken from the local timezone,
+    /// `ParserOptions.local_offset` or `ParserOptions.now`
+    Inferred,
+    /// The input is an instant without an offset, such as a unix timestamp, read in UTC
+    DefaultUtc,
+}
+
+/// Tells where the offset of the input read via the given branch comes from
+fn offset_origin(s: &str, via: ParsedVia) -> OffsetOrigin {
+    match via {
+        ParsedVia::UnixTimestamp => OffsetOrigin::DefaultUtc,
+        // /Date(1672903639123+0200)/ has an offset, ticks and /Date(1672903639123)/ do not
+        ParsedVia::DotNet => match s.split_once("Date(") {
+            Some((_, inner)) if inner.get(1..).is_some_and(|x| x.contains(['+', '-'])) => {
+                OffsetOrigin::Parsed
+            }
+            _ => OffsetOrigin::DefaultUtc,
+        },
+        _ if has_explicit_timezone(s) => OffsetOrigin::Parsed,
+        _ => OffsetOrigin::Inferred,
+    }
+}
+
+/// Warning is a non-fatal issue of a result, see `parse_best_effort`
+#[derive(Debug, Clone, PartialEq)]
+pub enum Warning {
+    /// The input has no year, or no date at all, so the year of the result was filled in
+    InferredYear(i32),
+    /// The input has no offset, so it was taken from the local timezone,
+    /// `ParserOptions.local_offset` or `ParserOptions.now`
+    InferredOffset,
+    /// The timezone abbreviation stands for more than one zone, eg: `CST` for
+    /// Central Standard Time or China Standard Time, and was read as `assumed`
+    AmbiguousAbbreviation {
+        abbreviation: String,
+        assumed: FixedOffset,
+    },
+    /// The input is more precise than the result, eg: a fraction of a second past
+    /// the nanoseconds or a decimal unix timestamp, and was truncated
+    TruncatedPrecision,
+}
+
+/// Lists the non-fatal issues of the input read via the given branch as `dt`
+fn warnings(s: &str, via: ParsedVia, dt: &DateTime<FixedOffset>) -> Vec<Warning> {
+    let mut warnings = Vec::new();
+    if matches!(
+        via,
+        ParsedVia::CompactTime
+            | ParsedVia::TimeWithoutTz
+            | ParsedVia::TimeWithTz
+            | ParsedVia::DayOffsetTime
+            | ParsedVia::Syslog
+            | ParsedVia::Others
+    ) {
+        warnings.push(Warning::InferredYear(dt.year()));
+    }
+    match offset_origin(s, via) {
+        OffsetOrigin::Inferred => warnings.push(Warning::InferredOffset),
+        OffsetOrigin::Parsed => {
+            let abbreviation = s
+                .split_whitespace()
+                .map(|token| token.trim_matches(|c: char| !c.is_ascii_alphabetic()))
+                .find(|token| tz::is_ambiguous(token));
+            if let Some((abbreviation, assumed)) =
+                abbreviation.and_then(|x| Some((x, tz::abbreviation_offset(x)?)))
+            {
+                warnings.push(Warning::AmbiguousAbbreviation {
+                    abbreviation: abbreviation.to_string(),
+                    assumed,
+                });
+            }
+        }
+        OffsetOrigin::DefaultUtc => {}
+    }
+    let truncated = match via {
+        ParsedVia::UnixTimestamp => s.contains('.'),
+        _ => s
+            .split(['.', ','])
+            .skip(1)
+            .any(|x| x.bytes().take_while(u8::is_ascii_digit).count() > 9),
+    };
+    if truncated {
+        warnings.push(Warning::TruncatedPrecision);
+    }
+    warnings
+}
+
+/// Order in which the parser branches are tried; the first branch to match wins
+/// An 8-digit number is read as a basic ISO date and an 18-digit number in the range of
+/// .NET ticks for 1970 to 2100 is read as ticks before either is tried as a timestamp
+const PIPELINE: &[ParsedVia] = &[
+    ParsedVia::YearOnly,
+    ParsedVia::BasicIso,
+    ParsedVia::DotNet,
+    ParsedVia::ExcelSerial,
+    ParsedVia::CompactTime,
+    ParsedVia::UnixTimestamp,
+    ParsedVia::Rfc3339,
+    ParsedVia::DatetimeWithTz,
+    ParsedVia::GoDatetime,
+    ParsedVia::HttpDate,
+    ParsedVia::Clf,
+    ParsedVia::DatetimeWithoutTz,
+    ParsedVia::MonthYear,
+    ParsedVia::Quarter,
+    ParsedVia::Week,
+    ParsedVia::Cjk,
+    ParsedVia::DateWithoutTz,
+    ParsedVia::TimeWithoutTz,
+    ParsedVia::TimeWithTz,
+    ParsedVia::DayOffsetTime,
+    ParsedVia::YmdHmsTz,
+    ParsedVia::DmmmyHmsTz,
+    ParsedVia::MmmddyyyyHmsTz,
+    ParsedVia::DatetimeWithTzBeforeYear,
+    ParsedVia::Syslog,
+    ParsedVia::Others,
+];
+
+/// Shape is a cheap first pass over the input, used to skip
+/// whole families of branches that cannot possibly match it
+struct Shape {
+    /// only digits after an optional sign, possibly with a decimal point
+    numeric: bool,
+    digit: bool,
+    alpha: bool,
+    colon: bool,
+    space: bool,
+    signed: bool,
+    /// a number with an explicit unit or prefix, eg: 1672903639123ms or epoch:1672903639
+    timestamp: bool,
+}
+
+impl Shape {
+    fn of(s: &str) -> Self {
+        let unsigned = s.strip_prefix(['+', '-']).unwrap_or(s);
+        Shape {
+            numeric: unsigned.starts_with(|c: char| c.is_ascii_digit())
+                && unsigned.chars().all(|c| c.is_ascii_digit() || c == '.'),
+            digit: s.chars().any(|c| c.is_ascii_digit()),
+            alpha: s.chars().any(char::is_alphabetic),
+            colon: s.contains(':'),
+            space: s.contains(' '),
+            signed: s.starts_with(['+', '-']),
+            timestamp: split_timestamp_unit(s).is_some(),
+        }
+    }
+}
+
+impl ParsedVia {
+    /// Whether the branch can match an input of the given shape.
+    /// Every format needs at least one digit and all formats with a time need a colon.
+    fn applies(self, shape: &Shape) -> bool {
+        match self {
+            ParsedVia::ExcelSerial => shape.numeric,
+            ParsedVia::UnixTimestamp => shape.numeric || shape.timestamp,
+            _ if !shape.digit => false,
+            ParsedVia::YearOnly => !shape.alpha && !shape.space && !shape.colon,
+            ParsedVia::BasicIso => !shape.space && !shape.colon,
+            ParsedVia::CompactTime => !shape.colon && !shape.signed,
+            ParsedVia::MonthYear => shape.space && !shape.colon,
+            ParsedVia::Quarter => shape.alpha && !shape.colon,
+            ParsedVia::Week => shape.alpha,
+            ParsedVia::Cjk => shape.alpha && !shape.colon && !shape.signed,
+            ParsedVia::DotNet => shape.numeric || (shape.alpha && !shape.space && !shape.colon),
+            ParsedVia::DateWithoutTz => true,
+            ParsedVia::Others => shape.alpha,
+            ParsedVia::Rfc3339
+            | ParsedVia::DatetimeWithTz
+            | ParsedVia::DatetimeWithoutTz
+            | ParsedVia::TimeWithoutTz
+            | ParsedVia::TimeWithTz
+            | ParsedVia::DatetimeWithTzBeforeYear => shape.colon,
+            ParsedVia::HttpDate
+            | ParsedVia::Clf
+            | ParsedVia::YmdHmsTz
+            | ParsedVia::DmmmyHmsTz
+            | ParsedVia::MmmddyyyyHmsTz
+            | ParsedVia::Syslog => shape.colon && shape.alpha,
+            ParsedVia::GoDatetime => shape.colon && shape.alpha && shape.space,
+            ParsedVia::DayOffsetTime => shape.colon && shape.signed,
+        }
+    }
+
+    /// Runs the branch on the standardized input
+    fn parse(self, s: &str, options: &ParserOptions) -> Result<DateTime<FixedOffset>, Error> {
+        match self {
+            ParsedVia::YearOnly => from_year_only(s, options),
+            ParsedVia::BasicIso => from_basic_iso(s, options),
+            ParsedVia::DotNet => from_dotnet(s),
+            ParsedVia::ExcelSerial => match options.excel_epoch {
+                Some(epoch) => from_excel_serial(s, epoch, options),
+                None => Err("spreadsheet serial dates are disabled".to_string()),
+            },
+            ParsedVia::CompactTime => from_compact_time(s, options),
+            // a 4-digit number that is not a valid `HHMM` is not a timestamp either
+            ParsedVia::UnixTimestamp
+                if options.compact_time
+                    && s.len() == 4
+                    && s.chars().all(|c| c.is_ascii_digit()) =>
+            {
+                Err("not a compact time".to_string())
+            }
+            ParsedVia::UnixTimestamp => from_unix_timestamp(s),
+            // `%+` reads the offset as `Z`, `±HH:MM` or `±HHMM` alike
+            ParsedVia::Rfc3339 => DateTime::parse_from_str(s, "%+").map_err(|e| e.to_string()),
+            ParsedVia::DatetimeWithTz => from_datetime_with_tz(s),
+            ParsedVia::GoDatetime => from_go_datetime(s),
+            ParsedVia::HttpDate => from_http_date(s),
+            ParsedVia::Clf => from_clf(s),
+            ParsedVia::DatetimeWithoutTz => from_datetime_without_tz(s, options),
+            ParsedVia::MonthYear => from_month_year(s, options),
+            ParsedVia::Quarter => from_quarter(s, options),
+            ParsedVia::Week => from_week(s, options),
+            ParsedVia::Cjk => from_cjk(s, options),
+            ParsedVia::DateWithoutTz => from_date_without_tz(s, options),
+            ParsedVia::TimeWithoutTz => from_time_without_tz(s, options),
+            ParsedVia::TimeWithTz => from_time_with_tz(s, options),
+            ParsedVia::DayOffsetTime => from_day_offset_time(s, options),
+            ParsedVia::YmdHmsTz => try_yms_hms_tz(s),
+            ParsedVia::DmmmyHmsTz => try_dmmmy_hms_tz(s),
+            ParsedVia::MmmddyyyyHmsTz => try_mmmddyyyy_hms_tz(s),
+            ParsedVia::DatetimeWithTzBeforeYear => from_datetime_with_tz_before_year(s),
+            ParsedVia::Syslog => from_syslog(s, options),
+            ParsedVia::Others => try_others(s, options),
+        }
+    }
+}
+
+/// Returns the branches the given options will try, in order
+fn pipeline(options: &ParserOptions) -> impl Iterator<Item = ParsedVia> + '_ {
+    PIPELINE
+        .iter()
+        .copied()
+        .filter(|via| *via != ParsedVia::YearOnly || !options.reject_year_only)
+        .filter(|via| *via != ParsedVia::ExcelSerial || options.excel_epoch.is_some())
+}
+
 /// parse_from interprets the input date/time slice and returns a normalised parsed date/time
-/// as DateTime<FixedOffset> or will return an Error
-fn parse_from(date_time: &str) -> Result<DateTime<FixedOffset>, Error> {
+/// as DateTime<FixedOffset> along with the branch that matched, or will return an Error
+fn parse_from(
+    date_time: &str,
+    options: &ParserOptions,
+) -> Result<(DateTime<FixedOffset>, ParsedVia), ParseError> {
+    let (dt, via) = parse_unbounded(date_time, options)?;
+    Ok((options.within_bounds(dt)?, via))
+}
+
+/// Parses the date/time string regardless of `ParserOptions.min` and `ParserOptions.max`,
+/// once unwrapped from a pair of quotes or brackets, see `unwrap_enclosing`
+fn parse_unbounded(
+    date_time: &str,
+    options: &ParserOptions,
+) -> Result<(DateTime<FixedOffset>, ParsedVia), ParseError> {
+    let date_time = unwrap_enclosing(date_time);
     if date_time.is_empty() {
-        Err("cannot be empty".to_string())
-    } else {
-        let date_time = standardize_date(date_time);
-        from_unix_timestamp(&date_time)
-            .or_else(|_| DateTime::parse_from_str(&date_time, "%+"))
-            .or_else(|_| from_datetime_with_tz(&date_time))
-            .or_else(|_| from_datetime_without_tz(&date_time))
-            .or_else(|_| from_date_without_tz(&date_time))
-            .or_else(|_| from_time_without_tz(&date_time))
-            .or_else(|_| from_time_with_tz(&date_time))
-            .or_else(|_| try_yms_hms_tz(&date_time))
-            .or_else(|_| try_dmmmy_hms_tz(&date_time))
-            .or_else(|_| try_mmmddyyyy_hms_tz(&date_time))
-            .or_else(|_| from_datetime_with_tz_before_year(&date_time))
-            .or_else(|_| try_others(&date_time))
+        return Err(ParseError::Empty);
+    }
+    if date_time.len() > options.max_input_len {
+        return Err(ParseError::TooLong {
+            unit: "bytes",
+            max: options.max_input_len,
+        });
+    }
+    if date_time
+        .split_whitespace()
+        .nth(options.max_tokens)
+        .is_some()
+    {
+        return Err(ParseError::TooLong {
+            unit: "tokens",
+            max: options.max_tokens,
+        });
+    }
+    // canonical RFC 3339 is by far the most common input, and needs neither
+    // localizing nor standardizing, nor the branches tried before it
+    if let Some(dt) = from_canonical_rfc3339(date_time, options) {
+        return Ok((dt, ParsedVia::Rfc3339));
+    }
+    let localized = options.localize(date_time);
+    let date_time = drop_redundant_zone(standardize_date(&localized))?;
+    let result = try_pipeline(&date_time, options);
+    if options.strict_ambiguity && matches!(result, Ok((_, ParsedVia::DateWithoutTz))) {
+        if let Some(e) = ambiguous_date(&date_time, options) {
+            return Err(e);
+        }
+    }
+    let error = match result {
+        Err(ParseError::Unrecognized(error)) => error,
+        result => return result,
+    };
+    // an ambiguous local time fails every branch like an unrecognized input does,
+    // it is told apart by resolving it to the earlier instant instead
+    if options.ambiguous == Ambiguous::Reject {
+        let earliest = ParserOptions {
+            ambiguous: Ambiguous::Earliest,
+            ..options.clone()
+        };
+        if try_pipeline(&date_time, &earliest).is_ok() {
+            return Err(ParseError::AmbiguousLocalTime(date_time.into_owned()));
+        }
+    }
+    if options.coerce_invalid {
+        if let Some(clamped) = range::clamp(&date_time, options) {
+            if let Ok(parsed) = try_pipeline(&clamped, options) {
+                return Ok(parsed);
+            }
+        }
+    }
+    Err(range::out_of_range(&date_time, options).unwrap_or(ParseError::Unrecognized(error)))
+}
+
+/// Reads a canonical RFC 3339 date/time as is, eg: 2023-01-05T07:27:19.123+02:00,
+/// giving what the `ParsedVia::Rfc3339` branch gives for it after `standardize_date`
+fn from_canonical_rfc3339(s: &str, options: &ParserOptions) -> Option<DateTime<FixedOffset>> {
+    let s = s.trim();
+    if s.as_bytes().get(10) != Some(&b'T')
+        || ParsedVia::Rfc3339.confidence() < options.min_confidence
+    {
+        return None;
+    }
+    let dt = DateTime::parse_from_rfc3339(s).ok()?;
+    Some(match options.output_timezone {
+        Some(tz) => dt.with_timezone(&tz),
+        None => dt,
+    })
+}
+
+/// Separators between the two ends of a range, checked before a plain `-`
+const RANGE_SEPARATORS: &[&str] = &[" to ", " until ", "..", "–"];
+
+/// Splits a range on the first known separator, or else on the first `-` with a
+/// date/time on both sides, and parses both ends
+fn parse_range_from(
+    s: &str,
+    options: &ParserOptions,
+) -> Result<(DateTime<FixedOffset>, DateTime<FixedOffset>), ParseError> {
+    // ascii lowercasing keeps the byte offsets of the original
+    let lower = s.to_ascii_lowercase();
+    let separator = RANGE_SEPARATORS
+        .iter()
+        .find_map(|sep| lower.find(sep).map(|idx| (idx, sep.len())));
+    let (start, end) = match separator {
+        Some((idx, len)) => (
+            parse_from(s[..idx].trim(), options)?.0,
+            parse_from(s[idx + len..].trim(), options)?.0,
+        ),
+        None => s
+            .match_indices('-')
+            .find_map(|(idx, _)| {
+                let start = parse_from(s[..idx].trim(), options).ok()?.0;
+                let end = parse_from(s[idx + 1..].trim(), options).ok()?.0;
+                Some((start, end))
+            })
+            .ok_or_else(|| ParseError::Unrecognized("no date range found".to_string()))?,
+    };
+    if options.reject_reversed_range && end < start {
+        return Err(ParseError::ReversedRange(s.to_string()));
+    }
+    Ok((start, end))
+}
+
+/// Parses a date, time and timezone given apart, see `parse_components`
+fn parse_components_from(
+    date: &str,
+    time: Option<&str>,
+    tz: Option<&str>,
+    options: &ParserOptions,
+) -> Result<DateTime<FixedOffset>, ParseError> {
+    let date = date.trim();
+    if date.is_empty() {
+        return Err(ParseError::Empty);
+    }
+    let time = time.map(str::trim).filter(|x| !x.is_empty());
+    let tz = match tz.map(str::trim).filter(|x| !x.is_empty()) {
+        Some(tz) => tz,
+        None => {
+            let s = time.map_or_else(|| date.to_string(), |time| format!("{} {}", date, time));
+            return parse_from(&s, options).map(|(dt, _)| dt);
+        }
+    };
+    let dt = format!("{} {}", date, time.unwrap_or("00:00:00"));
+    let dt = to_rfc2822(&standardize_date(&dt), tz).map_err(ParseError::Unrecognized)?;
+    options.within_bounds(match options.output_timezone {
+        Some(tz) => dt.with_timezone(&tz),
+        None => dt,
+    })
+}
+
+/// Parses an ISO 8601 interval, `start/end`, `start/duration` or `duration/end`
+fn parse_interval_from(
+    s: &str,
+    options: &ParserOptions,
+) -> Result<(DateTime<FixedOffset>, DateTime<FixedOffset>), ParseError> {
+    let (start, end) = s
+        .trim()
+        .split_once('/')
+        .ok_or_else(|| ParseError::Unrecognized("not an ISO 8601 interval".to_string()))?;
+    let out_of_range = || ParseError::OutOfRange {
+        component: "duration",
+        value: s.to_string(),
+    };
+    let (start, end) = match (duration::parse(start), duration::parse(end)) {
+        (Some(_), Some(_)) => {
+            return Err(ParseError::Unrecognized(
+                "an interval needs a start or an end".to_string(),
+            ))
+        }
+        (None, Some(duration)) => {
+            let start = parse_from(start, options)?.0;
+            (start, duration.after(start).ok_or_else(out_of_range)?)
+        }
+        (Some(duration), None) => {
+            let end = parse_from(end, options)?.0;
+            (duration.before(end).ok_or_else(out_of_range)?, end)
+        }
+        (None, None) => (parse_from(start, options)?.0, parse_from(end, options)?.0),
+    };
+    if options.reject_reversed_range && end < start {
+        return Err(ParseError::ReversedRange(s.to_string()));
+    }
+    Ok((start, end))
+}
+
+/// Runs the standardized input through the pipeline, returning the first match
+fn try_pipeline(
+    date_time: &str,
+    options: &ParserOptions,
+) -> Result<(DateTime<FixedOffset>, ParsedVia), ParseError> {
+    let shape = Shape::of(date_time);
+    let mut error = "failed brute force parsing".to_string();
+    for via in pipeline(options).filter(|via| via.applies(&shape)) {
+        match via.parse(date_time, options) {
+            Ok(_) if via.confidence() < options.min_confidence => {
+                return Err(ParseError::LowConfidence {
+                    via,
+                    confidence: via.confidence(),
+                })
+            }
+            Ok(dt) => {
+                let dt = match options.timestamp_target_offset {
+                    Some(tz) if offset_origin(date_time, via) == OffsetOrigin::DefaultUtc => {
+                        dt.with_timezone(&tz)
+                    }
+                    _ => dt,
+                };
+                match options.output_timezone {
+                    Some(tz) => return Ok((dt.with_timezone(&tz), via)),
+                    None => return Ok((dt, via)),
+                }
+            }
+            Err(e) => {
+                trace::record(&format!("{:?}", via), || e.clone());
+                // the branch that read the local time tells why it failed better
+                // than the branches tried after it
+                if !is_local_time_error(&error) {
+                    error = e;
+                }
+            }
+        }
+    }
+    Err(ParseError::Unrecognized(error))
+}
+
+/// Checks for the error of a local time that the system timezone skips or repeats,
+/// see `ParserOptions::local`
+fn is_local_time_error(e: &str) -> bool {
+    e.ends_with(" in the local timezone")
+}
+
+/// Tries each format in order and returns the first successful parse
+fn try_formats<T>(
+    formats: &[&str],
+    parse: impl Fn(&str) -> chrono::ParseResult<T>,
+) -> Result<T, Error> {
+    let mut error = None;
+    for format in formats {
+        match parse(format) {
+            Ok(x) => return Ok(x),
+            Err(e) => {
+                trace::record(format, || e.to_string());
+                error = Some(e);
+            }
+        }
     }
+    Err(error.map_or_else(|| "no format matched".to_string(), |e| e.to_string()))
+}
+
+/// Units of an explicitly scaled timestamp with the number of them in a second
+const TIMESTAMP_UNITS: &[(&str, i64)] =
+    &[("ns", 1000000000), ("us", 1000000), ("ms", 1000), ("s", 1)];
+
+/// Splits the number off a timestamp with an `epoch:` prefix or a unit suffix,
+/// eg: epoch:1672903639, 1672903639s or 1672903639123ms, returning the number of units
+/// in a second when there is a suffix
+fn split_timestamp_unit(s: &str) -> Option<(&str, Option<i64>)> {
+    let prefixed = s.strip_prefix("epoch:");
+    let s = prefixed.unwrap_or(s);
+    let (number, per_second) = match TIMESTAMP_UNITS
+        .iter()
+        .find_map(|(unit, per_second)| Some((s.strip_suffix(unit)?, Some(*per_second))))
+    {
+        Some(x) => x,
+        None if prefixed.is_some() => (s, None),
+        None => return None,
+    };
+    let unsigned = number.strip_prefix(['+', '-']).unwrap_or(number);
+    let numeric = unsigned.starts_with(|c: char| c.is_ascii_digit())
+        && unsigned.chars().all(|c| c.is_ascii_digit() || c == '.');
+    numeric.then_some((number, per_second))
 }
 
+/// Years within which a unix timestamp without a unit reads as a plausible date
+const TIMESTAMP_YEARS: core::ops::Range<i32> = 1000..3000;
+
+/// Convert a unix timestamp, eg: 1672903639, 1672903639123, 1672903639123ms or epoch:1672903639
+/// Unless given by a suffix, the unit is the first of seconds, milliseconds, microseconds
+/// and nanoseconds that gives a date within `TIMESTAMP_YEARS`. Up to 32503679999 (the end
+/// of 2999) is seconds, eg: 10413792000 is in 2300, then up to 14 digits is milliseconds,
+/// up to 17 digits microseconds and beyond that nanoseconds, whose range always fits.
+/// A small number is always seconds, eg: 500 is 00:08:20 on January 1st 1970,
+/// a few hundred milliseconds need the `ms` suffix, eg: 500ms
+/// The result is always in UTC, as a timestamp is an instant with no offset,
+/// see `ParserOptions.timestamp_target_offset`
 fn from_unix_timestamp(s: &str) -> Result<DateTime<FixedOffset>, Error> {
+    let (s, unit) = split_timestamp_unit(s).unwrap_or((s, None));
     let tts = if let Ok(s) = s.parse::<i64>().map_err(|e| e.to_string()) {
         s
     } else {
-        s.parse::<f64>().map_err(|e| e.to_string())? as i64
-    };
-    let dt = if tts <= 9999999999 {
-        //timestamp in seconds
-        chrono::naive::NaiveDateTime::from_timestamp_opt(tts, 0)
-    } else if tts <= 9999999999999 {
-        //timestamp in milliseconds
-        chrono::naive::NaiveDateTime::from_timestamp_opt(tts / 1000, (tts % 1000) as u32 * 1000000)
-    } else if tts <= 9999999999999999 {
-        //timestamp in microseconds
-        chrono::naive::NaiveDateTime::from_timestamp_opt(
-            tts / 1000000,
-            (tts % 1000000) as u32 * 1000,
-        )
-    } else {
-        //timestamp in nanoseconds
-        chrono::naive::NaiveDateTime::from_timestamp_opt(
-            tts / 1000000000,
-            (tts % 1000000000) as u32,
+        // `as` would silently saturate anything beyond the range of i64
+        match s.parse::<f64>().map_err(|e| e.to_string())? {
+            x if x.is_finite() && -(i64::MAX as f64) < x && x < i64::MAX as f64 => x as i64,
+            _ => return Err("timestamp out of range".to_string()),
+        }
+    };
+    // the euclidean division keeps the sub-second part positive,
+    // so pre-epoch (negative) timestamps are scaled alike
+    let at = |per_second: i64| {
+        DateTime::from_timestamp(
+            tts.div_euclid(per_second),
+            (tts.rem_euclid(per_second) * (1000000000 / per_second)) as u32,
         )
     };
+    let dt = match unit {
+        Some(per_second) => at(per_second),
+        None => [1, 1000, 1000000, 1000000000]
+            .into_iter()
+            .filter_map(at)
+            .find(|dt| TIMESTAMP_YEARS.contains(&dt.year())),
+    };
     Ok(chrono::DateTime::<FixedOffset>::from_naive_utc_and_offset(
-        dt.ok_or_else(|| "incorrect ts")?,
+        dt.ok_or("incorrect ts")?.naive_utc(),
         FixedOffset::east_opt(0).unwrap(),
     ))
 }
+
+/// Convert a bare 4-digit year to January 1st of that year with local timezone
+/// eg: 2023
+fn from_year_only(s: &str, options: &ParserOptions) -> Result<DateTime<FixedOffset>, Error> {
+    if s.len() != 4 || !s.chars().all(|c| c.is_ascii_digit()) {
+        return Err("not a year".to_string());
+    }
+    NaiveDate::from_ymd_opt(s.parse::<i32>().map_err(|e| e.to_string())?, 1, 1)
+        .ok_or_else(|| "incorrect year".to_string())
+        .and_then(|x| options.start_of_day(x))
+}
+
+const BASIC_ISO_WITH_TZ_FORMATS: &[&str] = &["%Y%m%dT%H%M%S%.f%z", "%Y%m%dT%H%M%z"];
+
+const BASIC_ISO_WITHOUT_TZ_FORMATS: &[&str] = &["%Y%m%dT%H%M%S%.f", "%Y%m%dT%H%M", "%Y%m%d-%H%M%S"];
+
+/// Convert the basic (separator free) ISO 8601 forms, with local timezone when no offset is given
+/// eg: 20230105T072719Z, 20230105T072719+0200 or 20230105
+/// and the dash separated filename timestamp, eg: 20230105-072719
+/// A bare 8-digit number is only a date when it is a valid calendar date,
+/// otherwise it is left for `from_unix_timestamp`
+fn from_basic_iso(s: &str, options: &ParserOptions) -> Result<DateTime<FixedOffset>, Error> {
+    if s.len() == 8 && s.chars().all(|c| c.is_ascii_digit()) {
+        let date = NaiveDate::parse_from_str(s, "%Y%m%d").map_err(|e| e.to_string())?;
+        return options.start_of_day(date);
+    }
+    let s = match s.strip_suffix(['Z', 'z']) {
+        Some(s) => Cow::Owned(format!("{}+0000", s)),
+        None => Cow::Borrowed(s),
+    };
+    if let Ok(dt) = try_formats(BASIC_ISO_WITH_TZ_FORMATS, |f| {
+        DateTime::parse_from_str(&s, f)
+    }) {
+        return Ok(dt);
+    }
+    let local = try_formats(BASIC_ISO_WITHOUT_TZ_FORMATS, |f| {
+        NaiveDateTime::parse_from_str(&s, f)
+    })?;
+    options.local(&local)
+}
+
+/// .NET ticks (100ns) at 1970-01-01 and at 2100-01-01
+const DOTNET_EPOCH_TICKS: i64 = 621_355_968_000_000_000;
+const DOTNET_MAX_TICKS: i64 = 662_380_416_000_000_000;
+
+/// Convert the .NET representations of an instant
+/// eg: /Date(1672903639123)/ or /Date(1672903639123+0200)/ (ASP.NET JSON, milliseconds since
+/// the unix epoch and the offset to display it in) or 638089107190000000 (`DateTime.Ticks`,
+/// 100 nanosecond intervals since 0001-01-01 UTC)
+fn from_dotnet(s: &str) -> Result<DateTime<FixedOffset>, Error> {
+    // the leading '/' has already been standardized to '-'
+    if let Some(inner) = s
+        .strip_prefix(['/', '-'])
+        .and_then(|x| x.strip_prefix("Date("))
+    {
+        let inner = inner
+            .strip_suffix(['/', '-'])
+            .unwrap_or(inner)
+            .strip_suffix(')')
+            .ok_or("not a .NET date")?;
+        let (ms, offset) = match inner.get(1..).and_then(|x| x.find(['+', '-'])) {
+            Some(idx) => inner.split_at(idx + 1),
+            None => (inner, "+0000"),
+        };
+        let offset = DateTime::parse_from_str(&format!("1970-01-01 00:00 {}", offset), "%F %R %z")
+            .map_err(|e| e.to_string())?;
+        let ms = ms.parse::<i64>().map_err(|e| e.to_string())?;
+        return DateTime::from_timestamp_millis(ms)
+            .map(|x| x.with_timezone(offset.offset()))
+            .ok_or_else(|| "incorrect ts".to_string());
+    }
+    if s.len() != 18 || !s.chars().all(|c| c.is_ascii_digit()) {
+        return Err("not a .NET date".to_string());
+    }
+    let ticks = s.parse::<i64>().map_err(|e| e.to_string())?;
+    if !(DOTNET_EPOCH_TICKS..DOTNET_MAX_TICKS).contains(&ticks) {
+        return Err("not .NET ticks".to_string());
+    }
+    let ticks = ticks - DOTNET_EPOCH_TICKS;
+    DateTime::from_timestamp(ticks / 10_000_000, (ticks % 10_000_000) as u32 * 100)
+        .map(|x| x.fixed_offset())
+        .ok_or_else(|| "incorrect ts".to_string())
+}
+
+/// The last serial date Excel can represent, 9999-12-31 in the 1900 system
+const EXCEL_MAX_SERIAL: f64 = 2958466.0;
+
+/// Convert a spreadsheet serial date number, where the fraction is the time of day,
+/// to Datetime fixed offset with local timezone
+/// eg: 44927.3107 is 2023-01-01 07:27:24.480 in the 1900 system
+/// The 1900 system counts the nonexistent 1900-02-29 as serial 60 (a Lotus 1-2-3 bug kept
+/// by Excel), so serials before it are one day ahead and 60 itself is rejected
+fn from_excel_serial(
+    s: &str,
+    epoch: ExcelEpoch,
+    options: &ParserOptions,
+) -> Result<DateTime<FixedOffset>, Error> {
+    let serial = s.parse::<f64>().map_err(|e| e.to_string())?;
+    if !(0.0..EXCEL_MAX_SERIAL).contains(&serial) {
+        return Err("not a spreadsheet serial date".to_string());
+    }
+    let days = serial as i64;
+    let base = match epoch {
+        ExcelEpoch::Epoch1900 if days == 60 => {
+            return Err("1900-02-29 does not exist".to_string());
+        }
+        ExcelEpoch::Epoch1900 if days < 60 => NaiveDate::from_ymd_opt(1899, 12, 31),
+        ExcelEpoch::Epoch1900 => NaiveDate::from_ymd_opt(1899, 12, 30),
+        ExcelEpoch::Epoch1904 => NaiveDate::from_ymd_opt(1904, 1, 1),
+    };
+    // spreadsheets keep the time of day to the millisecond
+    let ms = ((serial - days as f64) * 86_400_000.0 + 0.5) as i64;
+    let local = base.unwrap().and_hms_opt(0, 0, 0).unwrap()
+        + Duration::days(days)
+        + Duration::milliseconds(ms);
+    options.local(&local)
+}
+
+const DATETIME_WITH_TZ_FORMATS: &[&str] = &[
+    "%Y-%m-%dT%T%.f%z",
+    "%Y-%m-%dT%T%.f%#z",
+    // Ruby's Time#to_s and Time#inspect, eg: 2023-01-05 07:27:19.123456789 +0000
+    "%Y-%m-%dT%T%.f %z",
+    "%B %d %Y %T %#z",
+    "%B %d %Y %T%.f%#z",
+    "%A %d %B %Y %T%.f%#z",
+    "%A %d %B %Y %T %#z",
+    "%A %d %B %T %#z %Y",
+    "%A %B %d %T %#z %Y",
+    "%A %d %B %T%.f %#z %Y",
+    "%A %B %d %T%.f %#z %Y",
+    "%A %d %B %H:%M %#z %Y",
+    "%A %B %d %H:%M %#z %Y",
+    "%A %d %B %I:%M %P %#z %Y",
+    "%A %B %d %I:%M %P %#z %Y",
+    "%A %d %B %I:%M%P %#z %Y",
+    "%A %B %d %I:%M%P %#z %Y",
+];
+
 /// Convert a `datetime` string to `DateTime<FixedOffset>`
-fn from_datetime_with_tz(s: &str) -> Result<DateTime<FixedOffset>, ParseError> {
-    eprintln!("{s}");
-    DateTime::parse_from_rfc3339(s)
-        .or_else(|_| DateTime::parse_from_rfc2822(s))
-        .or_else(|_| DateTime::parse_from_str(s, "%Y-%m-%dT%T%.f%z"))
-        .or_else(|_| DateTime::parse_from_str(s, "%Y-%m-%d %T%#z"))
-        .or_else(|_| DateTime::parse_from_str(s, "%Y-%m-%d %T.%f%#z"))
-        .or_else(|_| DateTime::parse_from_str(s, "%B %d %Y %T %#z"))
-        .or_else(|_| DateTime::parse_from_str(s, "%B %d %Y %T.%f%#z"))
-        .or_else(|_| DateTime::parse_from_str(s, "%A %d %B %Y %T.%f%#z"))
-        .or_else(|_| DateTime::parse_from_str(s, "%A %d %B %Y %T %#z"))
-        .or_else(|_| DateTime::parse_from_str(s, "%A %d %B %T %#z %Y"))
-        .or_else(|_| DateTime::parse_from_str(s, "%A %B %d %T %#z %Y"))
-        .or_else(|_| DateTime::parse_from_str(s, "%A %d %B %T.%f %#z %Y"))
-        .or_else(|_| DateTime::parse_from_str(s, "%A %B %d %T.%f %#z %Y"))
-        .or_else(|_| DateTime::parse_from_str(s, "%A %d %B %H:%M %#z %Y"))
-        .or_else(|_| DateTime::parse_from_str(s, "%A %B %d %H:%M %#z %Y"))
-        .or_else(|_| DateTime::parse_from_str(s, "%A %d %B %I:%M %P %#z %Y"))
-        .or_else(|_| DateTime::parse_from_str(s, "%A %B %d %I:%M %P %#z %Y"))
-        .or_else(|_| DateTime::parse_from_str(s, "%A %d %B %I:%M%P %#z %Y"))
-        .or_else(|_| DateTime::parse_from_str(s, "%A %B %d %I:%M%P %#z %Y"))
+/// A leap second such as 2016-12-31T23:59:60Z is not clamped to `:59.999...`: the result
+/// carries chrono's leap second representation (nanosecond >= 1_000_000_000), so it
+/// has the timestamp of `:59` and still formats as `:60`
+fn from_datetime_with_tz(s: &str) -> Result<DateTime<FixedOffset>, Error> {
+    if let Some((dt, offset)) = split_seconds_offset(s) {
+        return NaiveDateTime::parse_from_str(dt, "%Y-%m-%dT%T%.f")
+            .ok()
+            .and_then(|x| offset.from_local_datetime(&x).single())
+            .ok_or_else(|| "custom parsing failed".to_string());
+    }
+    DateTime::parse_from_rfc3339(&expand_short_offset(s))
+        // chrono reads the military zones but `Z` as -0000, they are left to `to_rfc2822`
+        .or_else(|e| match s.rsplit_once(' ') {
+            Some((_, tz)) if tz != "Z" && tz::military_offset(tz).is_some() => Err(e),
+            _ => DateTime::parse_from_rfc2822(s),
+        })
+        .or_else(|_| try_formats(DATETIME_WITH_TZ_FORMATS, |f| DateTime::parse_from_str(s, f)))
+}
+
+/// Splits off a trailing offset carrying seconds, which `%z` cannot read
+/// eg: 1880-01-01 12:00:00 +00:06:42 (local mean time) to (1880-01-01 12:00:00, +00:06:42)
+fn split_seconds_offset(s: &str) -> Option<(&str, FixedOffset)> {
+    let idx = s.len().checked_sub(9)?;
+    let (dt, offset) = (s.get(..idx)?.trim_end(), s.get(idx..)?);
+    let bytes = offset.as_bytes();
+    let valid = matches!(bytes[0], b'+' | b'-')
+        && bytes[3] == b':'
+        && bytes[6] == b':'
+        && [1, 2, 4, 5, 7, 8]
+            .iter()
+            .all(|i| bytes[*i].is_ascii_digit());
+    if !valid || !dt.ends_with(|c: char| c.is_ascii_digit()) {
+        return None;
+    }
+    let field = |i: usize| offset[i..i + 2].parse::<i32>().unwrap_or_default();
+    let secs = field(1) * 3600 + field(4) * 60 + field(7);
+    let secs = if bytes[0] == b'-' { -secs } else { secs };
+    FixedOffset::east_opt(secs).map(|offset| (dt, offset))
+}
+
+/// Expand a trailing hour-only offset after a time to `±HH:00`
+/// eg: 2023-01-05T07:27:19+05 to 2023-01-05T07:27:19+05:00
+fn expand_short_offset(s: &str) -> Cow<'_, str> {
+    let split = s
+        .len()
+        .checked_sub(3)
+        .and_then(|i| Some((s.get(..i)?, s.get(i..)?)));
+    match split {
+        Some((time, offset))
+            if time.contains(':')
+                && time.ends_with(|c: char| c.is_ascii_digit())
+                && offset.starts_with(['+', '-'])
+                && offset[1..].chars().all(|c| c.is_ascii_digit()) =>
+        {
+            Cow::Owned(format!("{}:00", s))
+        }
+        _ => Cow::Borrowed(s),
+    }
+}
+
+/// Convert the default `time.Time.String()` output of Go, which carries both a numeric offset
+/// and a redundant zone abbreviation, and optionally a monotonic clock reading
+/// eg: 2006-01-02 15:04:05.999999999 -0700 MST m=+0.000000001
+fn from_go_datetime(s: &str) -> Result<DateTime<FixedOffset>, Error> {
+    let mut tokens = s.split_whitespace().collect::<Vec<_>>();
+    if tokens.last().is_some_and(|x| x.starts_with("m=")) {
+        tokens.pop();
+    }
+    // the date and time are joined by the `T` of `standardize_date`
+    if tokens.len() != 3 || !tokens[2].chars().all(char::is_alphabetic) {
+        return Err("custom parsing failed".to_string());
+    }
+    DateTime::parse_from_str(&tokens[..2].join(" "), "%Y-%m-%dT%H:%M:%S%.f %z")
+        .map_err(|e| e.to_string())
 }
 
+/// Convert the HTTP dates of RFC 7231 that carry the `GMT` literal
+/// eg: Sun, 06 Nov 1994 08:49:37 GMT (IMF-fixdate) or Sunday, 06-Nov-94 08:49:37 GMT (RFC 850)
+/// The asctime form `Sun Nov  6 08:49:37 1994` has no zone and is handled as a local datetime
+fn from_http_date(s: &str) -> Result<DateTime<FixedOffset>, Error> {
+    let dt = s
+        .strip_suffix(" GMT")
+        .ok_or_else(|| "custom parsing failed".to_string())?;
+    NaiveDateTime::parse_from_str(dt, "%a %d %b %Y %T")
+        .or_else(|_| NaiveDateTime::parse_from_str(dt, "%A %d-%b-%y %T"))
+        .map(|x| x.and_utc().fixed_offset())
+        .map_err(|e| e.to_string())
+}
+
+/// Convert the timestamps of the common log format written by Apache and Nginx,
+/// eg: 10/Oct/2000:13:55:36 -0700, with or without the surrounding brackets
+fn from_clf(s: &str) -> Result<DateTime<FixedOffset>, Error> {
+    let dt = s.strip_prefix('[').unwrap_or(s);
+    let dt = dt.strip_suffix(']').unwrap_or(dt);
+    if !is_clf(dt) {
+        return Err("not a common log format timestamp".to_string());
+    }
+    DateTime::parse_from_str(dt, "%d/%b/%Y:%T %z").map_err(|e| e.to_string())
+}
+
+/// Checks for the `dd/Mon/yyyy:` prefix of a common log format timestamp,
+/// whose slashes must survive `standardize_date`
+fn is_clf(s: &str) -> bool {
+    let s = s.strip_prefix('[').unwrap_or(s);
+    let date = match s.split_once(':') {
+        Some((date, _)) => date,
+        None => return false,
+    };
+    match date.split('/').collect::<Vec<_>>()[..] {
+        [day, month, year] => {
+            (1..=2).contains(&day.len())
+                && day.chars().all(|c| c.is_ascii_digit())
+                && month.len() == 3
+                && month.chars().all(|c| c.is_ascii_alphabetic())
+                && year.len() == 4
+                && year.chars().all(|c| c.is_ascii_digit())
+        }
+        _ => false,
+    }
+}
+
+const DATETIME_WITHOUT_TZ_FORMATS: &[&str] = &[
+    "%Y-%m-%dT%T",
+    "%c",
+    "%Y-%m-%dT%T%.f",
+    "%Y %b %d %T",
+    "%B %d %Y %T",
+    "%B %d %Y %T%.f",
+    "%B %d, %Y %T",
+    "%B %d, %Y %T%.f",
+    "%B %d %Y %H:%M",
+    "%B %d %Y %I:%M%P",
+    "%B %d %Y %I:%M %P",
+    "%B %d %Y %I:%M:%S%.f%P",
+    "%B %d %Y %I:%M:%S%.f %P",
+    "%Y-%m-%dT%I:%M%P",
+    "%Y-%m-%dT%I:%M %P",
+    "%Y-%m-%dT%I:%M:%S%.f%P",
+    "%Y-%m-%dT%I:%M:%S%.f %P",
+    "%A %d %B %Y %T%.f",
+    "%A %d %B %Y %T",
+    "%A %d %B %Y %I:%M%P",
+    "%A %d %B %Y %I:%M %P",
+    "%A %d %B %Y %I:%M:%S%P",
+    "%A %d %B %Y %I:%M:%S %P",
+    "%A %d %m %Y %I:%M%P",
+    "%A %d %m %Y %I:%M %P",
+    "%A %d %m %Y %I:%M:%S%P",
+    "%A %d %m %Y %I:%M:%S %P",
+    "%d %B %Y %H:%M",
+    "%d %B %Y %T",
+    "%d %B %Y %T%.f",
+    "%d %B %Y %I:%M%P",
+    "%d %B %Y %I:%M %P",
+    "%d %B %Y %I:%M:%S%P",
+    "%d %B %Y %I:%M:%S %P",
+    "%d %m %Y %I:%M%P",
+    "%d %m %Y %I:%M %P",
+    "%d %m %Y %I:%M:%S%P",
+    "%d %m %Y %I:%M:%S %P",
+    "%-m-%-d-%Y %-H:%-M:%-S %p",
+    "%d %b %Y %H:%M:%S",
+    "%m-%d-%Y:%H:%M:%S %Z",
+];
+
 /// Convert a `datetime` string, that which mostly does not have a timezone info
 /// to Datetime fixed offset with local timezone
-fn from_datetime_without_tz(s: &str) -> Result<DateTime<FixedOffset>, Error> {
-    NaiveDateTime::parse_from_str(s, "%Y-%m-%dT%T")
-        .or_else(|_| NaiveDateTime::parse_from_str(s, "%c"))
-        .or_else(|_| NaiveDateTime::parse_from_str(s, "%Y-%m-%dT%T.%f"))
-        .or_else(|_| NaiveDateTime::parse_from_str(s, "%Y-%m-%d %T"))
-        .or_else(|_| NaiveDateTime::parse_from_str(s, "%Y-%m-%d %T.%f"))
-        .or_else(|_| NaiveDateTime::parse_from_str(s, "%Y %b %d %T"))
-        .or_else(|_| NaiveDateTime::parse_from_str(s, "%B %d %Y %T"))
-        .or_else(|_| NaiveDateTime::parse_from_str(s, "%B %d %Y %T.%f"))
-        .or_else(|_| NaiveDateTime::parse_from_str(s, "%B %d, %Y %T"))
-        .or_else(|_| NaiveDateTime::parse_from_str(s, "%B %d, %Y %T.%f"))
-        .or_else(|_| NaiveDateTime::parse_from_str(s, "%Y-%m-%d %T"))
-        .or_else(|_| NaiveDateTime::parse_from_str(s, "%Y-%m-%d %T.%f"))
-        .or_else(|_| NaiveDateTime::parse_from_str(s, "%A %d %B %Y %T.%f"))
-        .or_else(|_| NaiveDateTime::parse_from_str(s, "%A %d %B %Y %T"))
-        .or_else(|_| NaiveDateTime::parse_from_str(s, "%A %d %B %Y %I:%M%P"))
-        .or_else(|_| NaiveDateTime::parse_from_str(s, "%A %d %B %Y %I:%M %P"))
-        .or_else(|_| NaiveDateTime::parse_from_str(s, "%A %d %B %Y %I:%M:%S%P"))
-        .or_else(|_| NaiveDateTime::parse_from_str(s, "%A %d %B %Y %I:%M:%S %P"))
-        .or_else(|_| NaiveDateTime::parse_from_str(s, "%A %d %m %Y %I:%M%P"))
-        .or_else(|_| NaiveDateTime::parse_from_str(s, "%A %d %m %Y %I:%M %P"))
-        .or_else(|_| NaiveDateTime::parse_from_str(s, "%A %d %m %Y %I:%M:%S%P"))
-        .or_else(|_| NaiveDateTime::parse_from_str(s, "%A %d %m %Y %I:%M:%S %P"))
-        .or_else(|_| NaiveDateTime::parse_from_str(s, "%d %B %Y %I:%M%P"))
-        .or_else(|_| NaiveDateTime::parse_from_str(s, "%d %B %Y %I:%M %P"))
-        .or_else(|_| NaiveDateTime::parse_from_str(s, "%d %B %Y %I:%M:%S%P"))
-        .or_else(|_| NaiveDateTime::parse_from_str(s, "%d %B %Y %I:%M:%S %P"))
-        .or_else(|_| NaiveDateTime::parse_from_str(s, "%d %m %Y %I:%M%P"))
-        .or_else(|_| NaiveDateTime::parse_from_str(s, "%d %m %Y %I:%M %P"))
-        .or_else(|_| NaiveDateTime::parse_from_str(s, "%d %m %Y %I:%M:%S%P"))
-        .or_else(|_| NaiveDateTime::parse_from_str(s, "%d %m %Y %I:%M:%S %P"))
-        .or_else(|_| NaiveDateTime::parse_from_str(s, "%-m-%-d-%Y %-H:%-M:%-S %p"))
-        .or_else(|_| NaiveDateTime::parse_from_str(s, "%d %b %Y %H:%M:%S"))
-        .or_else(|_| NaiveDateTime::parse_from_str(s, "%m-%d-%Y:%H:%M:%S %Z"))
-        .map(|x| Local.from_local_datetime(&x))
-        .map_err(|e| e.to_string())
-        .map(|x| x.unwrap().with_timezone(x.unwrap().offset()))
+/// Leap seconds (`23:59:60`) are kept the same way as in `from_datetime_with_tz`
+fn from_datetime_without_tz(
+    s: &str,
+    options: &ParserOptions,
+) -> Result<DateTime<FixedOffset>, Error> {
+    try_formats(DATETIME_WITHOUT_TZ_FORMATS, |f| {
+        NaiveDateTime::parse_from_str(s, f)
+    })
+    .and_then(|x| options.local(&x))
+}
+
+/// Convert a month period, named or numeric, to the 1st of that month with local timezone
+/// eg: January 2023, Jan 2023 or 01 2023
+fn from_month_year(s: &str, options: &ParserOptions) -> Result<DateTime<FixedOffset>, Error> {
+    let (month, year) = s.split_once(' ').ok_or("not a month period")?;
+    if year.len() != 4 || !year.chars().all(|c| c.is_ascii_digit()) {
+        return Err("not a month period".to_string());
+    }
+    let date = if month.chars().all(|c| c.is_ascii_digit()) {
+        NaiveDate::parse_from_str(&format!("{} 1 {}", month, year), "%m %d %Y")
+    } else {
+        NaiveDate::parse_from_str(&format!("{} 1 {}", month, year), "%B %d %Y")
+    }
+    .map_err(|e| e.to_string())?;
+    options.start_of_day(date)
+}
+
+/// Convert a quarter and a year, in either order, to the first day of the quarter
+/// at midnight in the local timezone
+/// eg: Q2 2023, 2023 Q2 or 2023-Q2 to 2023-04-01
+fn from_quarter(s: &str, options: &ParserOptions) -> Result<DateTime<FixedOffset>, Error> {
+    let (year, quarter) = split_quarter(s).ok_or("not a quarter")?;
+    if !(1..=4).contains(&quarter) {
+        return Err(format!("quarter out of range: {}", quarter));
+    }
+    let date = NaiveDate::from_ymd_opt(year, quarter * 3 - 2, 1).ok_or("not a quarter")?;
+    options.start_of_day(date)
+}
+
+/// Reads `Q<n>` (any case) and a 4 digit year separated by a space or a `-`
+/// into (year, quarter), without checking the quarter
+pub(crate) fn split_quarter(s: &str) -> Option<(i32, u32)> {
+    let (a, b) = s.split_once([' ', '-'])?;
+    let quarter = |x: &str| {
+        x.strip_prefix(['Q', 'q'])
+            .filter(|n| !n.is_empty() && n.chars().all(|c| c.is_ascii_digit()))
+            .and_then(|n| n.parse::<u32>().ok())
+    };
+    let year = |x: &str| {
+        (x.len() == 4 && x.chars().all(|c| c.is_ascii_digit()))
+            .then(|| x.parse::<i32>().ok())
+            .flatten()
+    };
+    match (quarter(a), quarter(b)) {
+        (Some(q), None) => Some((year(b)?, q)),
+        (None, Some(q)) => Some((year(a)?, q)),
+        _ => None,
+    }
+}
+
+/// Convert a week of the year to the Monday of that week at midnight in the local
+/// timezone, numbering the weeks per `ParserOptions.week_numbering`
+/// eg: Week 5 2023, 2023 W5 or 2023-W05 to 2023-01-30 (ISO)
+/// and the ISO 8601 week dates, see `from_week_date`
+fn from_week(s: &str, options: &ParserOptions) -> Result<DateTime<FixedOffset>, Error> {
+    let Some((year, week)) = split_week(s) else {
+        return from_week_date(s, options);
+    };
+    let date = week_monday(year, week, options.week_numbering)
+        .ok_or_else(|| format!("week out of range: {}", week))?;
+    options.start_of_day(date)
+}
+
+const WEEK_DATE_FORMATS: &[&str] = &["%G-W%V-%u", "%GW%V%u"];
+
+const WEEK_TIME_FORMATS: &[&str] = &["%H:%M:%S%.f", "%H:%M", "%H%M%S%.f", "%H%M"];
+
+/// Convert an ISO 8601 week date (ISO weeks whatever `ParserOptions.week_numbering` is),
+/// in the extended or basic form, with an optional time and offset, and local timezone
+/// when no offset is given
+/// eg: 2023-W05-1, 2023W051, 2023-W05-1T07:27:19Z or 2023W051T072719+0200
+fn from_week_date(s: &str, options: &ParserOptions) -> Result<DateTime<FixedOffset>, Error> {
+    let (date, time) = s.split_once(['T', ' ']).unwrap_or((s, ""));
+    let date = try_formats(WEEK_DATE_FORMATS, |f| NaiveDate::parse_from_str(date, f))?;
+    if time.is_empty() {
+        return options.start_of_day(date);
+    }
+    let (time, zone) = time.split_at(time.find(['Z', '+', '-']).unwrap_or(time.len()));
+    let x = date.and_time(try_formats(WEEK_TIME_FORMATS, |f| {
+        NaiveTime::parse_from_str(time, f)
+    })?);
+    let offset = match zone {
+        "" => return options.local(&x),
+        "Z" => FixedOffset::east_opt(0),
+        zone => fixed_offset(zone),
+    };
+    offset
+        .and_then(|offset| offset.from_local_datetime(&x).single())
+        .ok_or_else(|| format!("incorrect offset: {}", zone))
 }
 
+/// Delimiters following each of the year, month, day, hour, minute and second
+/// in Chinese and Japanese dates, in order
+const CJK_DELIMITERS: [&[char]; 6] = [
+    &['年'],
+    &['月'],
+    &['日'],
+    &['时', '時', '点'],
+    &['分'],
+    &['秒'],
+];
+
+/// Convert a date written with the Chinese/Japanese delimiters, optionally followed by
+/// a time, to Datetime fixed offset with local timezone
+/// eg: 2023年01月05日, 2023年1月5日 7时27分 or 2023年1月5日 7時27分19秒
+fn from_cjk(s: &str, options: &ParserOptions) -> Result<DateTime<FixedOffset>, Error> {
+    let mut fields = [0; 6];
+    let mut count = 0;
+    let mut number = None;
+    for c in s.chars().filter(|c| !c.is_whitespace()) {
+        if let Some(digit) = c.to_digit(10) {
+            number = Some(
+                number
+                    .unwrap_or(0u32)
+                    .saturating_mul(10)
+                    .saturating_add(digit),
+            );
+            continue;
+        }
+        let delimiter = CJK_DELIMITERS.get(count).filter(|x| x.contains(&c));
+        match (delimiter, number.take()) {
+            (Some(_), Some(n)) => fields[count] = n,
+            _ => return Err("not a CJK date".to_string()),
+        }
+        count += 1;
+    }
+    // the last delimiter may be left out after the hour, eg: 7点30
+    if let Some(n) = number {
+        if !(4..6).contains(&count) {
+            return Err("not a CJK date".to_string());
+        }
+        fields[count] = n;
+        count += 1;
+    }
+    if count < 3 {
+        return Err("not a CJK date".to_string());
+    }
+    let [year, month, day, hour, minute, second] = fields;
+    NaiveDate::from_ymd_opt(year as i32, month, day)
+        .and_then(|x| x.and_hms_opt(hour, minute, second))
+        .ok_or_else(|| "incorrect CJK date".to_string())
+        .and_then(|x| options.local(&x))
+}
+
+/// Reads `Week <n> <year>` (any case), or `W<n>` next to a 4 digit year separated by
+/// a space or a `-`, into (year, week), without checking the week
+pub(crate) fn split_week(s: &str) -> Option<(i32, u32)> {
+    let number = |x: &str| {
+        (!x.is_empty() && x.chars().all(|c| c.is_ascii_digit()))
+            .then(|| x.parse::<u32>().ok())
+            .flatten()
+    };
+    let year = |x: &str| (x.len() == 4).then(|| number(x)).flatten();
+    let week = |x: &str| x.strip_prefix(['W', 'w']).and_then(number);
+    match s.split([' ', '-']).collect::<Vec<_>>()[..] {
+        [label, n, y] if label.eq_ignore_ascii_case("week") => Some((year(y)? as i32, number(n)?)),
+        [a, b] => match (week(a), week(b)) {
+            (Some(n), None) => Some((year(b)? as i32, n)),
+            (None, Some(n)) => Some((year(a)? as i32, n)),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Returns the Monday of the week, or `None` when the year has no such week
+pub(crate) fn week_monday(year: i32, week: u32, numbering: WeekNumbering) -> Option<NaiveDate> {
+    match numbering {
+        WeekNumbering::Iso => NaiveDate::from_isoywd_opt(year, week, Weekday::Mon),
+        WeekNumbering::Us => {
+            let first = NaiveDate::from_ymd_opt(year, 1, 1)?;
+            let last = NaiveDate::from_ymd_opt(year, 12, 31)?;
+            // the Sunday starting week 1, which can fall in the previous year
+            let sunday = first - Duration::days(first.weekday().num_days_from_sunday() as i64);
+            let weeks = (last - sunday).num_days() / 7 + 1;
+            (1..=weeks)
+                .contains(&(week as i64))
+                .then(|| sunday + Duration::days(7 * (week as i64 - 1) + 1))
+        }
+    }
+}
+
+const DATE_FORMATS: &[&str] = &[
+    "%Y-%m-%d",
+    "%D",
+    "%F",
+    "%v",
+    "%B %d %Y",
+    "%d %B %Y",
+    "%A %B %d %Y",
+    "%A %d %B %Y",
+];
+
 /// Convert just `date` string without time or timezone information to Datetime fixed offset with local timezone
-fn from_date_without_tz(s: &str) -> Result<DateTime<FixedOffset>, Error> {
-    NaiveDate::parse_from_str(s, "%Y-%m-%d")
-        .or_else(|_| NaiveDate::parse_from_str(s, "%m-%d-%y"))
-        .or_else(|_| NaiveDate::parse_from_str(s, "%D"))
-        .or_else(|_| NaiveDate::parse_from_str(s, "%F"))
-        .or_else(|_| NaiveDate::parse_from_str(s, "%v"))
-        .or_else(|_| NaiveDate::parse_from_str(s, "%B %d %Y"))
-        .or_else(|_| NaiveDate::parse_from_str(s, "%d %B %Y"))
-        .map(|x| x.and_hms_opt(0, 0, 0).unwrap())
-        .map(|x| Local.from_local_datetime(&x))
-        .map_err(|e| e.to_string())
-        .map(|x| x.unwrap().with_timezone(x.unwrap().offset()))
+/// Numeric dates are read month first unless `ParserOptions.date_order` is `DateOrder::Dmy`
+/// eg: 05-01-23 is May 1st 2023 (MDY) or January 5th 2023 (DMY)
+fn from_date_without_tz(s: &str, options: &ParserOptions) -> Result<DateTime<FixedOffset>, Error> {
+    try_formats(&numeric_date_formats(options.date_order), |f| {
+        parse_numeric_date(s, f, options)
+    })
+    .or_else(|_| try_formats(DATE_FORMATS, |f| NaiveDate::parse_from_str(s, f)))
+    .and_then(|x| options.start_of_day(x))
 }
 
+/// Parses a numeric date, with a two-digit year in the century set by
+/// `ParserOptions.two_digit_year_pivot` whether the format has `%y` or `%Y`,
+/// eg: 05-06-49 is May 6th 2049 or 1949
+fn parse_numeric_date(s: &str, f: &str, options: &ParserOptions) -> chrono::ParseResult<NaiveDate> {
+    match s.rsplit_once('-') {
+        Some((head, yy)) if yy.len() == 2 && yy.chars().all(|c| c.is_ascii_digit()) => {
+            let year = options.two_digit_year(yy.parse().unwrap_or_default());
+            // only February 29th can be missing from the century, eg: 02-29-00 in 1900
+            NaiveDate::parse_from_str(&format!("{}-{}", head, year), &f.replace("%y", "%Y"))
+        }
+        _ => NaiveDate::parse_from_str(s, f),
+    }
+}
+
+/// Returns the numeric date formats with the ones of the given order first
+fn numeric_date_formats(order: DateOrder) -> [&'static str; 4] {
+    match order {
+        DateOrder::Mdy => ["%m-%d-%y", "%m-%d-%Y", "%d-%m-%y", "%d-%m-%Y"],
+        DateOrder::Dmy => ["%d-%m-%y", "%d-%m-%Y", "%m-%d-%y", "%m-%d-%Y"],
+    }
+}
+
+/// Returns `ParseError::Ambiguous` when a numeric date reads as more than one date,
+/// listing them in the order of `ParserOptions.date_order`
+fn ambiguous_date(s: &str, options: &ParserOptions) -> Option<ParseError> {
+    let mut candidates = Vec::new();
+    // a 2-digit year reads the same with `%y` and `%Y`,
+    // so only the first reading of each order counts
+    for formats in numeric_date_formats(options.date_order).chunks(2) {
+        let candidate = formats
+            .iter()
+            .find_map(|f| parse_numeric_date(s, f, options).ok())
+            .and_then(|x| options.start_of_day(x).ok())
+            .map(DateTimeFixedOffset);
+        if let Some(candidate) = candidate.filter(|x| !candidates.contains(x)) {
+            candidates.push(candidate);
+        }
+    }
+    (candidates.len() > 1).then_some(ParseError::Ambiguous { candidates })
+}
+
+const TIME_FORMATS: &[&str] = &[
+    "%T%.f",
+    "%I:%M%P",
+    "%I:%M %P",
+    "%I:%M:%S%P",
+    "%I:%M:%S %P",
+    "%I:%M:%S%.f%P",
+    "%I:%M:%S%.f %P",
+];
+
 /// Convert just `time` string without date or timezone information
 /// to Datetime fixed offset with local timezone & current date
-fn from_time_without_tz(s: &str) -> Result<DateTime<FixedOffset>, Error> {
-    NaiveTime::parse_from_str(s, "%T")
-        .or_else(|_| NaiveTime::parse_from_str(s, "%I:%M%P"))
-        .or_else(|_| NaiveTime::parse_from_str(s, "%I:%M %P"))
-        .map(|x| Local::now().date_naive().and_time(x))
-        .map(|x| Local.from_local_datetime(&x))
-        .map_err(|e| e.to_string())
-        .map(|x| x.unwrap().with_timezone(x.unwrap().offset()))
+/// (both taken from `ParserOptions.local_offset` and `now` when set)
+fn from_time_without_tz(s: &str, options: &ParserOptions) -> Result<DateTime<FixedOffset>, Error> {
+    try_formats(TIME_FORMATS, |f| NaiveTime::parse_from_str(s, f))
+        .and_then(|x| Ok(options.now()?.date_naive().and_time(x)))
+        .and_then(|x| options.local(&x))
+}
+
+/// Convert a compact 24-hour (military) time, `HHMM` followed by a zone, with the current date
+/// eg: 1430Z, 1430R or 1430 PST
+/// A bare 1430 is a unix timestamp unless `ParserOptions.compact_time` is set
+fn from_compact_time(s: &str, options: &ParserOptions) -> Result<DateTime<FixedOffset>, Error> {
+    let (time, zone) = match (s.get(..4), s.get(4..)) {
+        (Some(time), Some(zone)) if time.chars().all(|c| c.is_ascii_digit()) => {
+            (time, zone.trim_start())
+        }
+        _ => return Err("not a compact time".to_string()),
+    };
+    let time = NaiveTime::parse_from_str(time, "%H%M").map_err(|e| e.to_string())?;
+    let x = options.now()?.date_naive().and_time(time);
+    match zone {
+        "" if options.compact_time => options.local(&x),
+        "" => Err("compact times without a zone are disabled".to_string()),
+        zone => zone_offset(zone)
+            .and_then(|offset| offset.from_local_datetime(&x).single())
+            .ok_or_else(|| "not a compact time".to_string()),
+    }
 }
 
+const TIME_WITH_OFFSET_FORMATS: &[&str] = &["%T%.f%z", "%T%.f %z", "%H:%M%z", "%H:%M %z"];
+
 /// Convert just `time` string without date but timezone information
 /// to Datetime fixed offset with local timezone & current date
-fn from_time_with_tz(s: &str) -> Result<DateTime<FixedOffset>, Error> {
+/// A numeric offset, eg: 07:27:19.123+02:00, takes the current date in that offset
+fn from_time_with_tz(s: &str, options: &ParserOptions) -> Result<DateTime<FixedOffset>, Error> {
     if let Some((dt, tz)) = is_tz_alpha(s) {
-        let date = format!("{} {}", Local::now().format("%Y-%m-%d"), dt);
-        to_rfc2822(&date, tz)
-    } else {
-        Err("custom parsing failed".to_string())
+        let date = format!("{}T{}", options.now()?.format("%Y-%m-%d"), dt);
+        return to_rfc2822(&date, tz);
     }
+    if !s.contains(['+', '-']) {
+        return Err("custom parsing failed".to_string());
+    }
+    // any date reads the time and offset, which then picks the current date
+    let x = format!("1970-01-01T{}", s);
+    let dt = try_formats(TIME_WITH_OFFSET_FORMATS, |f| {
+        DateTime::parse_from_str(&x, &format!("%Y-%m-%dT{}", f))
+    })?;
+    let date = options.now()?.with_timezone(dt.offset()).date_naive();
+    dt.offset()
+        .from_local_datetime(&date.and_time(dt.time()))
+        .single()
+        .ok_or_else(|| "incorrect time".to_string())
+}
+
+/// Convert a signed day offset followed by a time, relative to the reference date
+/// eg: +1d 09:00 (tomorrow at 9am) or -2d 18:30:00
+fn from_day_offset_time(s: &str, options: &ParserOptions) -> Result<DateTime<FixedOffset>, Error> {
+    let (days, time) = s
+        .split_once(' ')
+        .ok_or_else(|| "custom parsing failed".to_string())?;
+    let days = days
+        .strip_suffix('d')
+        .filter(|x| x.starts_with(['+', '-']))
+        .ok_or_else(|| "custom parsing failed".to_string())?
+        .parse::<i64>()
+        .map_err(|e| e.to_string())?;
+    let time = try_formats(&["%H:%M", "%T", "%I:%M%P", "%I:%M %P"], |f| {
+        NaiveTime::parse_from_str(time, f)
+    })?;
+    let now = options.now()?;
+    let date = now
+        .date_naive()
+        .checked_add_signed(Duration::days(days))
+        .ok_or("incorrect day offset")?;
+    now.offset()
+        .from_local_datetime(&date.and_time(time))
+        .single()
+        .ok_or_else(|| "incorrect day offset".to_string())
 }
 
 /// Convert datetime with timezone information before the year
-/// eg: Wed Jul 1, 3:33pm PST 1970
+/// eg: Wed Jul 1, 3:33pm PST 1970 or the output of GNU `date`, which pads the day with
+/// a space and has a 12-hour clock in some locales: Thu Jan  5 07:27:19 AM UTC 2023
 fn from_datetime_with_tz_before_year(s: &str) -> Result<DateTime<FixedOffset>, Error> {
     let tokens = s.split_whitespace().collect::<Vec<_>>();
     if tokens.len() < 2 {
@@ -209,6 +1870,7 @@ fn from_datetime_with_tz_before_year(s: &str) -> Result<DateTime<FixedOffset>, E
 /// Try to parse the following types of dates
 /// 1970-12-25 16:16:16 PST
 /// 1970-12-25 16:16 PST
+/// 1970-12-25 16:16:16 Pacific Standard Time
 fn try_yms_hms_tz(s: &str) -> Result<DateTime<FixedOffset>, Error> {
     if let Some((dt, tz)) = is_tz_alpha(s) {
         to_rfc2822(dt, tz)
@@ -221,6 +1883,7 @@ fn try_yms_hms_tz(s: &str) -> Result<DateTime<FixedOffset>, Error> {
 /// 1 Jan 1970 22:00:00 PDT
 /// 1 Jan, 1970 22:00:00.000 PDT
 /// 1 Jan, 1970; 22:00:00 PDT
+/// 1 Jan 1970 22:00:00 GMT-3
 fn try_dmmmy_hms_tz(s: &str) -> Result<DateTime<FixedOffset>, Error> {
     if let Some((dt, tz)) = is_tz_alpha(s) {
         to_rfc2822(dt, tz)
@@ -229,230 +1892,618 @@ fn try_dmmmy_hms_tz(s: &str) -> Result<DateTime<FixedOffset>, Error> {
     }
 }
 
+const MMMDDYYYY_HMS_TZ_FORMATS: &[&str] = &[
+    "%B %d %Y %H:%M:%S%.f %z",
+    "%B %d %Y %I:%M:%S%P %z",
+    "%B %d %Y %I:%M:%S %P %z",
+    "%A %B %d %Y %H:%M:%S %z",
+    "%A %B %d %Y %I:%M%P %z",
+    "%A %B %d %Y %I:%M %P %z",
+    "%Y-%m-%dT%H:%M:%S%.f %z",
+    "%d %B %Y %H:%M:%S%.f %z",
+];
+
 // Feb 14 2022 13:13:55 GMT+00:00
 // Feb 14 2022 13:13:55 GMT+0000
 // Wed Jul 1 1970 13:13:55 GMT+0000
+// Feb 14 2022 13:13:55 PST-08
+// 2023-01-05 07:27:19 IST +05:30
+// The numeric offset always wins over the zone name next to it
 fn try_mmmddyyyy_hms_tz(s: &str) -> Result<DateTime<FixedOffset>, Error> {
-    let tz = s.rsplitn(2, ' ').take(2).collect::<Vec<_>>()[0].replace("GMT", "");
-    let dt = if s.rsplitn(2, ' ').count() > 1 {
-        s.rsplitn(2, ' ').take(2).collect::<Vec<_>>()[1].to_string()
-    } else {
-        return Err("custom parsing failed".to_string());
+    let (dt, tz) = s.rsplit_once(' ').ok_or("custom parsing failed")?;
+    let offset = numeric_offset(tz).ok_or("custom parsing failed")?;
+    let dt = match dt.rsplit_once(' ') {
+        Some((x, name))
+            if tz.starts_with(['+', '-'])
+                && name.chars().all(char::is_alphabetic)
+                && !name.eq_ignore_ascii_case("am")
+                && !name.eq_ignore_ascii_case("pm") =>
+        {
+            x
+        }
+        _ => dt,
     };
-    if !tz.is_empty() {
-        let x = dt + " " + &tz.replace(':', "");
-        DateTime::parse_from_str(&x, "%B %d %Y %H:%M:%S %z")
-            .or_else(|_| DateTime::parse_from_str(&x, "%B %d %Y %I:%M:%S%P %z"))
-            .or_else(|_| DateTime::parse_from_str(&x, "%B %d %Y %I:%M:%S %P %z"))
-            .or_else(|_| DateTime::parse_from_str(&x, "%A %B %d %Y %H:%M:%S %z"))
-            .or_else(|_| DateTime::parse_from_str(&x, "%A %B %d %Y %I:%M%P %z"))
-            .or_else(|_| DateTime::parse_from_str(&x, "%A %B %d %Y %I:%M %P %z"))
-            .map_err(|e| e.to_string())
-    } else {
-        Err("custom parsing failed".to_string())
+    let x = format!("{} {}", dt, offset);
+    try_formats(MMMDDYYYY_HMS_TZ_FORMATS, |f| {
+        DateTime::parse_from_str(&x, f)
+    })
+}
+
+/// Reads the numeric offset of a zone token as `±HHMM`, dropping any zone name in front
+/// eg: GMT+05:30 to +0530 or UTC-3 to -0300
+fn numeric_offset(tz: &str) -> Option<String> {
+    let offset = tz.trim_start_matches(char::is_alphabetic);
+    let sign = offset.get(..1).filter(|x| *x == "+" || *x == "-")?;
+    let digits = offset[1..].replace(':', "");
+    if !digits.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    match digits.len() {
+        1 | 2 => Some(format!("{}{:0>2}00", sign, digits)),
+        4 => Some(format!("{}{}", sign, digits)),
+        _ => None,
     }
 }
 
+/// Convert a BSD syslog (RFC 3164) timestamp, which has no year and pads the day with
+/// a space, eg: Oct 11 22:14:15 or Oct  1 22:14:15
+/// As with `try_others`, the year is the one of the reference date, see `max_future_days`
+/// and `assume_year`
+fn from_syslog(s: &str, options: &ParserOptions) -> Result<DateTime<FixedOffset>, Error> {
+    let (month, day, time) = match s.split(' ').collect::<Vec<_>>()[..] {
+        [month, day, time] if month.len() == 3 && (1..=2).contains(&day.len()) => {
+            (month, day, time)
+        }
+        _ => return Err("not a syslog timestamp".to_string()),
+    };
+    let x = format!("{} {} {} {}", options.year()?, month, day, time);
+    NaiveDateTime::parse_from_str(&x, "%Y %b %d %H:%M:%S%.f")
+        .map_err(|e| e.to_string())
+        .and_then(|x| options.recent_year(x))
+        .and_then(|x| options.local(&x))
+}
+
 /// Try to parse the following types of dates
 /// Feb 12 12:12:12 or Feb 12, 12:12
 /// Feb 12 or 12 Feb
-fn try_others(s: &str) -> Result<DateTime<FixedOffset>, Error> {
+fn try_others(s: &str, options: &ParserOptions) -> Result<DateTime<FixedOffset>, Error> {
     let date = s.split_whitespace().collect::<Vec<_>>();
-    let year = Local::now().year();
-    if date.len().eq(&2) && date[0].chars().all(char::is_alphabetic) {
+    let year = options.year()?;
+    let naive = if date.len().eq(&2) && date[0].chars().all(char::is_alphabetic) {
         // trying Feb 12
-        NaiveDate::parse_from_str(&format!("{} {}", s, year), "%B %d %Y")
+        let x = format!("{} {}", s, year);
+        try_formats(&["%B %d %Y"], |f| NaiveDate::parse_from_str(&x, f))
             .map(|x| x.and_hms_opt(0, 0, 0).unwrap())
-            .map(|x| Local.from_local_datetime(&x))
-            .map_err(|e| e.to_string())
-            .map(|x| x.unwrap().with_timezone(x.unwrap().offset()))
     } else if date.len().eq(&2) && date[1].chars().all(char::is_alphabetic) {
         // trying 12 Feb
-        NaiveDate::parse_from_str(&format!("{} {}", s, year), "%d %B %Y")
+        let x = format!("{} {}", s, year);
+        try_formats(&["%d %B %Y"], |f| NaiveDate::parse_from_str(&x, f))
             .map(|x| x.and_hms_opt(0, 0, 0).unwrap())
-            .map(|x| Local.from_local_datetime(&x))
-            .map_err(|e| e.to_string())
-            .map(|x| x.unwrap().with_timezone(x.unwrap().offset()))
     } else if date.len().eq(&3) && date[0].replace(',', "").chars().all(char::is_alphabetic) {
         // trying Feb 12 14:00:01 or Feb 12, 14:00:01 or Feb 12 14:00
-        NaiveDateTime::parse_from_str(
-            &format!("{} {} {} {}", date[0], date[1], year, date[2]),
-            "%B %d %Y %H:%M",
-        )
-        .or_else(|_| {
-            NaiveDateTime::parse_from_str(
-                &format!("{} {} {} {}", date[0], date[1], year, date[2]),
+        let x = format!("{} {} {} {}", date[0], date[1], year, date[2]);
+        try_formats(
+            &[
+                "%B %d %Y %H:%M",
                 "%b %d %Y %H:%M",
-            )
-        })
-        .or_else(|_| {
-            NaiveDateTime::parse_from_str(
-                &format!("{} {} {} {}", date[0], date[1], year, date[2]),
                 "%B %d %Y %T",
-            )
-        })
-        .or_else(|_| {
-            NaiveDateTime::parse_from_str(
-                &format!("{} {} {} {}", date[0], date[1], year, date[2]),
                 "%b %d %Y %T",
-            )
-        })
-        .or_else(|_| {
-            NaiveDateTime::parse_from_str(
-                &format!("{} {} {} {}", date[0], date[1], year, date[2]),
                 "%b %d %Y %T%.f",
-            )
-        })
-        .or_else(|_| {
-            NaiveDateTime::parse_from_str(
-                &format!("{} {} {} {}", date[0], date[1], year, date[2]),
-                "%B %d %Y %T",
-            )
-        })
-        .or_else(|_| {
-            NaiveDateTime::parse_from_str(
-                &format!("{} {} {} {}", date[0], date[1], year, date[2]),
                 "%B %d %Y %I:%M%P",
-            )
-        })
-        .map(|x| Local.from_local_datetime(&x))
-        .map_err(|e| e.to_string())
-        .map(|x| x.unwrap().with_timezone(x.unwrap().offset()))
+            ],
+            |f| NaiveDateTime::parse_from_str(&x, f),
+        )
     } else if date.len().eq(&3) && date[1].chars().all(char::is_alphabetic) {
         // trying 12 Feb 14:00:01 or 12 Feb, 14:00:01 or 12 Feb 14:00
-        NaiveDateTime::parse_from_str(
-            &format!("{} {} {} {}", date[0], date[1], year, date[2]),
-            "%d %B %Y %H:%M",
+        let x = format!("{} {} {} {}", date[0], date[1], year, date[2]);
+        try_formats(
+            &["%d %B %Y %H:%M", "%d %B %Y %T", "%d %B %Y %I:%M%P"],
+            |f| NaiveDateTime::parse_from_str(&x, f),
         )
-        .or_else(|_| {
-            NaiveDateTime::parse_from_str(
-                &format!("{} {} {} {}", date[0], date[1], year, date[2]),
-                "%d %B %Y %T",
-            )
-        })
-        .or_else(|_| {
-            NaiveDateTime::parse_from_str(
-                &format!("{} {} {} {}", date[0], date[1], year, date[2]),
-                "%d %B %Y %I:%M%P",
-            )
-        })
-        .map(|x| Local.from_local_datetime(&x))
-        .map_err(|e| e.to_string())
-        .map(|x| x.unwrap().with_timezone(x.unwrap().offset()))
     } else if date.len().eq(&4) && date[0].chars().all(char::is_alphabetic) {
         // trying Feb 12 3:33 pm
-        NaiveDateTime::parse_from_str(
-            &format!("{} {} {} {} {}", date[0], date[1], year, date[2], date[3]),
-            "%B %d %Y %I:%M %P",
-        )
-        .map(|x| Local.from_local_datetime(&x))
-        .map_err(|e| e.to_string())
-        .map(|x| x.unwrap().with_timezone(x.unwrap().offset()))
+        let x = format!("{} {} {} {} {}", date[0], date[1], year, date[2], date[3]);
+        try_formats(&["%B %d %Y %I:%M %P"], |f| {
+            NaiveDateTime::parse_from_str(&x, f)
+        })
     } else if date.len().eq(&4) && date[1].chars().all(char::is_alphabetic) {
         // trying 12 Feb 3:33 pm
-        NaiveDateTime::parse_from_str(
-            &format!("{} {} {} {} {}", date[0], date[1], year, date[2], date[3]),
-            "%d %B %Y %I:%M %P",
-        )
-        .map(|x| Local.from_local_datetime(&x))
-        .map_err(|e| e.to_string())
-        .map(|x| x.unwrap().with_timezone(x.unwrap().offset()))
+        let x = format!("{} {} {} {} {}", date[0], date[1], year, date[2], date[3]);
+        try_formats(&["%d %B %Y %I:%M %P"], |f| {
+            NaiveDateTime::parse_from_str(&x, f)
+        })
     } else {
         Err("failed brute force parsing".to_string())
-    }
+    }?;
+    options.local(&options.recent_year(naive)?)
 }
 
-/// Checks if the last characters are alphabet and assumes it to be TimeZone
+/// Number of words a spelled-out zone name can span, eg: Australian Eastern Standard Time
+const MAX_TZ_WORDS: usize = 4;
+
+/// Checks if the last words are alphabet and assumes them to be TimeZone
 /// and returns the tuple of (date_part, timezone_part)
+/// eg: 16:16:16 PST, 16:16:16 Pacific Standard Time or 16:16:16 GMT+0200
 fn is_tz_alpha(s: &str) -> Option<(&str, &str)> {
-    let mut dtz = s.trim().rsplitn(2, ' ');
-    let tz = dtz.next().unwrap_or_default();
-    let dt = dtz.next().unwrap_or_default();
-    if tz.chars().all(char::is_alphabetic) {
-        Some((dt, tz))
-    } else {
-        None
+    let s = s.trim();
+    let (dt, tz) = s.rsplit_once(' ').unwrap_or(("", s));
+    if is_gmt_offset(tz) {
+        return Some((dt, tz));
+    }
+    let mut start = s.len();
+    for _ in 0..MAX_TZ_WORDS {
+        let (head, word) = s[..start].rsplit_once(' ').unwrap_or(("", &s[..start]));
+        // a trailing meridiem belongs to the time, eg: 7:27 pm
+        let meridiem = word.eq_ignore_ascii_case("am") || word.eq_ignore_ascii_case("pm");
+        if word.is_empty() || !word.chars().all(char::is_alphabetic) || meridiem {
+            break;
+        }
+        start = head.len();
+    }
+    (start < s.len()).then(|| (&s[..start], s[start..].trim_start()))
+}
+
+/// Drops a `Z`, `UTC` or `GMT` (all `GMT` after `standardize_date`) that repeats the
+/// numeric offset in front of it, eg: 2023-01-05T07:27:19+00:00Z or 07:27:19 +0000 UTC,
+/// and fails when the offset is not UTC, eg: 2023-01-05T07:27:19+02:00Z
+/// Only an offset following a time counts, not the year of a date, eg: 12-13-2000 GMT
+fn drop_redundant_zone(s: Cow<'_, str>) -> Result<Cow<'_, str>, ParseError> {
+    let head = match s.strip_suffix(" GMT").or_else(|| s.strip_suffix('Z')) {
+        Some(head) => head.trim_end(),
+        None => return Ok(s),
+    };
+    let after_time = |idx: usize| {
+        head[..idx]
+            .trim_end()
+            .rsplit(' ')
+            .next()
+            .is_some_and(|x| x.contains(':'))
+    };
+    let utc = match head
+        .rfind(['+', '-'])
+        .filter(|idx| after_time(*idx))
+        .map(|idx| &head.as_bytes()[idx + 1..])
+    {
+        // 07:27:19Z GMT
+        _ if s.ends_with(" GMT") && head.ends_with('Z') => true,
+        Some([h1, h2, b':', m1, m2] | [h1, h2, m1, m2])
+            if [h1, h2, m1, m2].iter().all(|c| c.is_ascii_digit()) =>
+        {
+            [h1, h2, m1, m2].iter().all(|c| **c == b'0')
+        }
+        _ => return Ok(s),
+    };
+    if !utc {
+        return Err(ParseError::ConflictingZone(s.into_owned()));
+    }
+    let len = head.len();
+    Ok(match s {
+        Cow::Borrowed(s) => Cow::Borrowed(&s[..len]),
+        Cow::Owned(mut s) => {
+            s.truncate(len);
+            Cow::Owned(s)
+        }
+    })
+}
+
+/// Checks for a `GMT` or `UTC` prefixed numeric offset, eg: GMT+0200 or UTC-3
+fn is_gmt_offset(tz: &str) -> bool {
+    tz.strip_prefix("GMT")
+        .or_else(|| tz.strip_prefix("UTC"))
+        .and_then(numeric_offset)
+        .is_some()
+}
+
+const RFC2822_NAIVE_FORMATS: &[&str] = &[
+    "%Y-%m-%dT%H:%M:%S%.f",
+    "%Y-%m-%dT%I:%M%P",
+    "%Y-%m-%dT%I:%M %P",
+    "%Y-%m-%dT%I:%M:%S%P",
+    "%Y-%m-%dT%I:%M:%S %P",
+    "%Y-%m-%dT%H:%M",
+    "%d %B %Y %H:%M",
+    "%d %B %Y %T",
+    "%d %B %Y %T%.f",
+    "%A %d %B %Y %H:%M",
+    "%B %d %Y %H:%M",
+    "%B %d %Y %T",
+    "%B %d %Y %T%.f",
+    "%B %d %Y %I:%M:%S%P",
+    "%B %d %Y %I:%M:%S %P",
+    "%A %B %d %Y %T%.f",
+    "%A %B %d %Y %T",
+    "%A %d %B %Y %T",
+    "%A %d %B %Y %T%.f",
+    "%A %d %m %Y %T%.f",
+    "%A %d %m %Y %T",
+    "%A %d %m %T%.f %Y",
+    "%A %d %m %T %Y",
+    "%A %d %B %T%.f %Y",
+    "%A %d %B %T %Y",
+    "%A %B %d %T%.f %Y",
+    "%A %B %d %T %Y",
+    "%A %m %d %H:%M %Y",
+    "%A %d %m %H:%M %Y",
+    "%A %d %B %H:%M %Y",
+    "%A %B %d %H:%M %Y",
+    "%A %m %d %I:%M%P %Y",
+    "%A %d %m %I:%M%P %Y",
+    "%A %d %B %I:%M %P %Y",
+    "%A %d %B %I:%M%P %Y",
+    "%A %B %d %I:%M %P %Y",
+    "%A %B %d %I:%M%P %Y",
+    "%A %B %d %I:%M:%S %P %Y",
+    "%A %B %d %I:%M:%S%P %Y",
+    "%d %m %T%.f %Y",
+    "%d %m %T %Y",
+    "%d %B %T%.f %Y",
+    "%d %B %T %Y",
+    "%B %d %T%.f %Y",
+    "%B %d %T %Y",
+    "%m %d %I:%M %Y",
+    "%d %m %I:%M %Y",
+    "%d %B %I:%M %Y",
+    "%B %d %I:%M %Y",
+    "%m %d %I:%M%P %Y",
+    "%d %m %I:%M%P %Y",
+    "%d %B %I:%M %P %Y",
+    "%d %B %I:%M%P %Y",
+    "%B %d %I:%M %P %Y",
+    "%B %d %I:%M%P %Y",
+];
+
+/// Resolves a zone abbreviation, a spelled-out zone name, a military zone letter or a `GMT±offset` token
+/// to its fixed offset
+fn zone_offset(tz: &str) -> Option<FixedOffset> {
+    if let Some(offset) = tz::abbreviation_offset(tz)
+        .or_else(|| tz::name_offset(tz))
+        .or_else(|| tz::military_offset(tz))
+    {
+        return Some(offset);
+    }
+    if !is_gmt_offset(tz) {
+        return None;
     }
+    fixed_offset(tz)
+}
+
+/// Resolves a numeric offset, eg: +02:00, -0530 or GMT+2, to its fixed offset
+fn fixed_offset(tz: &str) -> Option<FixedOffset> {
+    let offset = numeric_offset(tz)?;
+    let field = |range| offset.get(range).and_then(|x: &str| x.parse::<i32>().ok());
+    let secs = field(1..3)? * 3600 + field(3..5)? * 60;
+    FixedOffset::east_opt(if offset.starts_with('-') { -secs } else { secs })
 }
 
-/// Convert the given date/time and timezone information into RFC 2822 format
+/// Convert the given date/time and timezone information into RFC 2822 format,
+/// keeping the precision of the date/time, eg: 16:16 is 16:16:00 and 16:16:16.250 keeps its fraction
 fn to_rfc2822(s: &str, tz: &str) -> Result<DateTime<FixedOffset>, Error> {
-    NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S")
-        .or_else(|_| NaiveDateTime::parse_from_str(s, "%Y-%m-%d %I:%M%P"))
-        .or_else(|_| NaiveDateTime::parse_from_str(s, "%Y-%m-%d %I:%M %P"))
-        .or_else(|_| NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M"))
-        .or_else(|_| NaiveDateTime::parse_from_str(s, "%d %B %Y %T"))
-        .or_else(|_| NaiveDateTime::parse_from_str(s, "%d %B %Y %T.%f"))
-        .or_else(|_| NaiveDateTime::parse_from_str(s, "%B %d %Y %H:%M"))
-        .or_else(|_| NaiveDateTime::parse_from_str(s, "%B %d %Y %T"))
-        .or_else(|_| NaiveDateTime::parse_from_str(s, "%B %d %Y %T.%f"))
-        .or_else(|_| NaiveDateTime::parse_from_str(s, "%A %B %d %Y %T.%f"))
-        .or_else(|_| NaiveDateTime::parse_from_str(s, "%A %B %d %Y %T"))
-        .or_else(|_| NaiveDateTime::parse_from_str(s, "%A %d %B %Y %T"))
-        .or_else(|_| NaiveDateTime::parse_from_str(s, "%A %d %B %Y %T.%f"))
-        .or_else(|_| NaiveDateTime::parse_from_str(s, "%A %d %m %Y %T.%f"))
-        .or_else(|_| NaiveDateTime::parse_from_str(s, "%A %d %m %Y %T"))
-        .or_else(|_| NaiveDateTime::parse_from_str(s, "%A %d %m %Y %T"))
-        .or_else(|_| NaiveDateTime::parse_from_str(s, "%A %d %m %Y %T.%f"))
-        .or_else(|_| NaiveDateTime::parse_from_str(s, "%A %d %m %T.%f %Y"))
-        .or_else(|_| NaiveDateTime::parse_from_str(s, "%A %d %m %T %Y"))
-        .or_else(|_| NaiveDateTime::parse_from_str(s, "%A %d %B %T.%f %Y"))
-        .or_else(|_| NaiveDateTime::parse_from_str(s, "%A %d %B %T %Y"))
-        .or_else(|_| NaiveDateTime::parse_from_str(s, "%A %B %d %T.%f %Y"))
-        .or_else(|_| NaiveDateTime::parse_from_str(s, "%A %B %d %T %Y"))
-        .or_else(|_| NaiveDateTime::parse_from_str(s, "%A %m %d %H:%M %Y"))
-        .or_else(|_| NaiveDateTime::parse_from_str(s, "%A %d %m %H:%M %Y"))
-        .or_else(|_| NaiveDateTime::parse_from_str(s, "%A %d %B %H:%M %Y"))
-        .or_else(|_| NaiveDateTime::parse_from_str(s, "%A %B %d %H:%M %Y"))
-        .or_else(|_| NaiveDateTime::parse_from_str(s, "%A %m %d %I:%M%P %Y"))
-        .or_else(|_| NaiveDateTime::parse_from_str(s, "%A %d %m %I:%M%P %Y"))
-        .or_else(|_| NaiveDateTime::parse_from_str(s, "%A %d %B %I:%M %P %Y"))
-        .or_else(|_| NaiveDateTime::parse_from_str(s, "%A %d %B %I:%M%P %Y"))
-        .or_else(|_| NaiveDateTime::parse_from_str(s, "%A %B %d %I:%M %P %Y"))
-        .or_else(|_| NaiveDateTime::parse_from_str(s, "%A %B %d %I:%M%P %Y"))
-        .or_else(|_| NaiveDateTime::parse_from_str(s, "%d %m %T.%f %Y"))
-        .or_else(|_| NaiveDateTime::parse_from_str(s, "%d %m %T %Y"))
-        .or_else(|_| NaiveDateTime::parse_from_str(s, "%d %B %T.%f %Y"))
-        .or_else(|_| NaiveDateTime::parse_from_str(s, "%d %B %T %Y"))
-        .or_else(|_| NaiveDateTime::parse_from_str(s, "%B %d %T.%f %Y"))
-        .or_else(|_| NaiveDateTime::parse_from_str(s, "%B %d %T %Y"))
-        .or_else(|_| NaiveDateTime::parse_from_str(s, "%m %d %I:%M %Y"))
-        .or_else(|_| NaiveDateTime::parse_from_str(s, "%d %m %I:%M %Y"))
-        .or_else(|_| NaiveDateTime::parse_from_str(s, "%d %B %I:%M %Y"))
-        .or_else(|_| NaiveDateTime::parse_from_str(s, "%B %d %I:%M %Y"))
-        .or_else(|_| NaiveDateTime::parse_from_str(s, "%m %d %I:%M%P %Y"))
-        .or_else(|_| NaiveDateTime::parse_from_str(s, "%d %m %I:%M%P %Y"))
-        .or_else(|_| NaiveDateTime::parse_from_str(s, "%d %B %I:%M %P %Y"))
-        .or_else(|_| NaiveDateTime::parse_from_str(s, "%d %B %I:%M%P %Y"))
-        .or_else(|_| NaiveDateTime::parse_from_str(s, "%B %d %I:%M %P %Y"))
-        .or_else(|_| NaiveDateTime::parse_from_str(s, "%B %d %I:%M%P %Y"))
-        .and_then(|x| {
-            DateTime::parse_from_rfc2822(
+    if tz == "J" {
+        return Err("the J military zone is the local time of the observer".to_string());
+    }
+    try_formats(RFC2822_NAIVE_FORMATS, |f| {
+        NaiveDateTime::parse_from_str(s, f)
+    })
+    .and_then(|x| {
+        // chrono reads the other zones, eg: -0800, from a whole RFC 2822 date, which only
+        // gives the offset so that the fraction of a second is not lost to `%S`
+        let offset = match zone_offset(tz) {
+            Some(offset) => offset,
+            None => *DateTime::parse_from_rfc2822(
                 (x.format("%a, %d %b %Y %H:%M:%S").to_string() + " " + tz).as_str(),
             )
+            .map_err(|e| e.to_string())?
+            .offset(),
+        };
+        offset
+            .from_local_datetime(&x)
+            .single()
+            .ok_or_else(|| "incorrect datetime".to_string())
+    })
+}
+
+/// Quotes and brackets that values extracted from JSON or logs arrive wrapped in
+const ENCLOSING: &[(char, char)] = &[
+    ('"', '"'),
+    ('\'', '\''),
+    ('`', '`'),
+    ('[', ']'),
+    ('(', ')'),
+    ('{', '}'),
+    ('<', '>'),
+];
+
+/// Strips a single matched pair of quotes or brackets around the input along with
+/// the whitespace inside them, eg: "2023-01-05T07:27:19Z" or [2023-01-05 07:27:19],
+/// leaving a lone or nested one as is, eg: [2023-01-05 or [[2023-01-05]]
+fn unwrap_enclosing(s: &str) -> &str {
+    let trimmed = s.trim();
+    ENCLOSING
+        .iter()
+        .find_map(|&(open, close)| {
+            trimmed
+                .strip_prefix(open)?
+                .strip_suffix(close)
+                .filter(|inner| !inner.contains([open, close]))
         })
-        .map_err(|e| e.to_string())
+        .map_or(s, str::trim)
+}
+
+/// Checks for the invisible format characters that get copied along from web pages,
+/// such as the byte order mark, zero-width spaces/joiners and bidi marks
+fn is_invisible(c: char) -> bool {
+    matches!(
+        c,
+        '\u{00AD}'
+            | '\u{200B}'..='\u{200F}'
+            | '\u{202A}'..='\u{202E}'
+            | '\u{2060}'..='\u{2064}'
+            | '\u{2066}'..='\u{2069}'
+            | '\u{FEFF}'
+    )
+}
+
+/// Maps the Unicode look-alikes of `-`, `/` and `:` that word processors and
+/// copy-pasting bring in to their ASCII counterpart, eg: an en dash or a minus sign to `-`
+fn ascii_punctuation(c: char) -> char {
+    match c {
+        '\u{2010}'..='\u{2015}' | '\u{2212}' | '\u{FE58}' | '\u{FE63}' | '\u{FF0D}' => '-',
+        '\u{2044}' | '\u{2215}' | '\u{FF0F}' => '/',
+        '\u{2236}' | '\u{A789}' | '\u{FE55}' | '\u{FF1A}' => ':',
+        c => c,
+    }
+}
+
+/// Drops a trailing parenthesised zone name when an offset or zone precedes it
+/// eg: Wed Jul 01 1970 13:13:55 GMT+0000 (Coordinated Universal Time)
+/// or 2023-01-05 07:27:19 +0200 (CEST), and otherwise takes a known name as the zone
+/// eg: 2023-01-05 07:27:19 (CEST) to 2023-01-05 07:27:19 CEST
+fn strip_zone_name(s: &str) -> Cow<'_, str> {
+    let s = s.trim_end();
+    let (head, name) = match s.rfind(" (") {
+        Some(idx) if s.ends_with(')') => (s[..idx].trim_end(), &s[idx + 2..s.len() - 1]),
+        _ => return Cow::Borrowed(s),
+    };
+    if has_zone(head) || zone_offset(name).is_none() {
+        return Cow::Borrowed(head);
+    }
+    Cow::Owned(format!("{} {}", head, name))
+}
+
+/// Month abbreviations as `%b` reads them
+const MONTH_ABBREVIATIONS: [&str; 12] = [
+    "jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec",
+];
+
+/// Informal month and weekday abbreviations that `%b` and `%a` reject,
+/// each of which starts with its canonical 3 letter form
+const INFORMAL_ABBREVIATIONS: [&str; 4] = ["sept", "tues", "thur", "thurs"];
+
+/// Drops the period of an abbreviated month (any case) and shortens the informal
+/// abbreviations to their canonical form, eg: Jan. 5, 2023 to Jan 5, 2023,
+/// Sept. 5 2023 to Sep 5 2023 or Tues, 5 Sept 2023 to Tue 5 Sep 2023
+fn abbreviation(token: &str) -> &str {
+    let word = token.trim_end_matches(',');
+    let word = word.strip_suffix('.').unwrap_or(word);
+    let is = |words: &[&str]| words.iter().any(|w| w.eq_ignore_ascii_case(word));
+    if is(&INFORMAL_ABBREVIATIONS) || is(&MONTH_ABBREVIATIONS) && word != token {
+        return &word[..3];
+    }
+    token
+}
+
+/// Replaces the `noon` and `midnight` keywords (any case) with the time they stand for
+fn time_keyword(token: &str) -> &str {
+    if token.eq_ignore_ascii_case("noon") {
+        "12:00:00"
+    } else if token.eq_ignore_ascii_case("midnight") {
+        "00:00:00"
+    } else {
+        token
+    }
+}
+
+/// Replaces a dotted `a.m.`/`p.m.` (any case, the last dot being optional), alone or
+/// glued to the time, with `am`/`pm`. Only the suffix is rewritten, so the decimal point
+/// of the seconds is kept, eg: 8:23:50.5 p.m.
+fn meridiem(token: &str) -> Cow<'_, str> {
+    let dotted = |suffix: &str| {
+        let suffix = suffix.as_bytes();
+        matches!(suffix[0].to_ascii_lowercase(), b'a' | b'p')
+            && suffix[1] == b'.'
+            && suffix[2].eq_ignore_ascii_case(&b'm')
+            && suffix.get(3).is_none_or(|x| *x == b'.')
+    };
+    let len = token.len();
+    match [4, 3]
+        .into_iter()
+        .find(|n| len >= *n && token.is_char_boundary(len - n) && dotted(&token[len - n..]))
+    {
+        Some(n) => {
+            let (time, suffix) = token.split_at(len - n);
+            Cow::Owned(format!("{}{}m", time, &suffix[..1]).to_lowercase())
+        }
+        None => Cow::Borrowed(token),
+    }
 }
 
 /// converts date/time string from having '.' or '/' to '-'
 /// and remove extra characters like ',', ';'
+/// except for a decimal comma after the seconds, eg: 07:27:19,123 to 07:27:19.123
 /// eg: 12/13/2000 to 12-13-2000 or 12/13/2000 12:12:12.14 to 12-13-2000 12:12:12.14
-fn standardize_date(s: &str) -> String {
-    if s.len() < 8 {
-        s.to_string()
-    } else {
-        s.chars()
-            .take(8)
-            .map(|mut x| {
-                if x.eq(&'.') || x.eq(&'/') {
-                    x = '-'
-                };
-                x
-            })
-            .collect::<String>()
-            + &s[8..]
+/// and a dot following a time stays a decimal point, eg: 8:23:50.5 pm
+/// Runs of whitespace (tabs, non-breaking spaces, ...) are collapsed into a single space
+/// and invisible characters such as a leading BOM are trimmed
+/// A standalone `at` between the date and the time is dropped, eg: 5 January 2023 at 14:30
+/// and the `noon`/`midnight` keywords are spelled out, eg: Jan 5 2023 midnight
+/// The dotted meridiem loses its dots, eg: 7:27:19 a.m. PDT to 7:27:19 am PDT
+/// An abbreviated month loses its period and informal abbreviations become canonical,
+/// eg: Jan. 5, 2023, Sept 5 2023 or Tues, 5 Sept 2023
+/// An ISO date and time are always joined by `T`, eg: 2023-01-05 07:27:19 to 2023-01-05T07:27:19
+/// Unicode dashes, slashes and colons become ASCII, eg: 2023–01–05 (en dashes) to 2023-01-05
+/// A `UT` or `UTC` zone token becomes `GMT`, eg: 07:27:19 UTC+2 to 07:27:19 GMT+2,
+/// while words merely starting with them are kept, eg: UTOPIA
+/// The input is borrowed as is when none of the above applies, and otherwise
+/// rewritten in a single pass
+fn standardize_date(s: &str) -> Cow<'_, str> {
+    let stripped = strip_zone_name(s.trim_matches(is_invisible));
+    if is_standard(&stripped) {
+        return stripped;
+    }
+    let s = stripped.as_ref();
+    let tokens = || {
+        s.split_whitespace()
+            .map(abbreviation)
+            .map(time_keyword)
+            .map(meridiem)
+    };
+    // the first 8 characters of the joined tokens get their separators rewritten, unless
+    // the input is that short, a decimal number such as 44927.3107 or a common log format
+    // timestamp such as 10/Oct/2000:13:55:36 -0700
+    let len = tokens()
+        .map(|x| x.len() + 1)
+        .sum::<usize>()
+        .saturating_sub(1);
+    let trimmed = s.trim();
+    let decimal = trimmed.chars().all(|c| c.is_ascii_digit() || c == '.')
+        && trimmed.matches('.').count() == 1;
+    let map_separators = len >= 8 && !decimal && !is_clf(trimmed);
+
+    let mut out = String::with_capacity(s.len());
+    let mut pos = 0;
+    let mut dropped_at = false;
+    // a dot after the time is a decimal point, eg: 8:23:50.5 pm
+    let mut time = false;
+    let mut tokens = tokens().enumerate().peekable();
+    while let Some((i, token)) = tokens.next() {
+        if i > 0 {
+            pos += 1;
+            // a standalone `at` between two tokens, the next one is then kept
+            if token == "at" && !dropped_at && tokens.peek().is_some() {
+                dropped_at = true;
+                pos += 2;
+                continue;
+            }
+            out.push(' ');
+        }
+        dropped_at = false;
+        let rest = match token
+            .strip_prefix("UTC")
+            .or_else(|| token.strip_prefix("UT"))
+        {
+            // only a whole `UT`/`UTC` token, or one followed by an offset, eg: UTC+2
+            Some(rest) if i > 0 && (rest.is_empty() || rest.starts_with(['+', '-'])) => {
+                out.push_str("GMT");
+                pos += token.len() - rest.len();
+                rest
+            }
+            _ => &token,
+        };
+        let mut chars = rest.chars().map(ascii_punctuation).peekable();
+        while let Some(c) = chars.next() {
+            match c {
+                // ISO 8601 also takes a comma as the decimal sign of the seconds
+                ',' if ends_with_seconds(&out)
+                    && chars.peek().is_some_and(char::is_ascii_digit) =>
+                {
+                    out.push('.')
+                }
+                ',' | ';' => {}
+                '.' | '/' if map_separators && pos < 8 && !time => out.push('-'),
+                c => {
+                    time |= c == ':';
+                    out.push(c)
+                }
+            }
+            pos += 1;
+        }
+    }
+    canonicalize_iso_separator(&mut out);
+    Cow::Owned(out)
+}
+
+/// Checks whether a date/time ends with the minutes and seconds of a time, eg: 07:27:19
+fn ends_with_seconds(s: &str) -> bool {
+    let digit = |b: &u8| b.is_ascii_digit();
+    match s.as_bytes() {
+        [.., b':', m1, m2, b':', s1, s2] => [m1, m2, s1, s2].into_iter().all(digit),
+        _ => false,
+    }
+}
+
+/// Checks whether `standardize_date` would leave the input untouched,
+/// erring on the side of `false`
+fn is_standard(s: &str) -> bool {
+    s.split(' ').all(|token| {
+        !token.is_empty()
+            && !token.contains(char::is_whitespace)
+            && abbreviation(token) == token
+            && time_keyword(token) == token
+            && matches!(meridiem(token), Cow::Borrowed(_))
+    }) && (!s.chars().take(8).any(|c| c == '.' || c == '/') || is_clf(s))
+        && !s.contains([',', ';'])
+        && !s.contains(" UT")
+        && !s.contains(" at ")
+        && s.chars().all(|c| ascii_punctuation(c) == c)
+        && noncanonical_iso_separator(s).is_none()
+}
+
+/// Splits the expanded year of ISO 8601, a sign and 6 digits, off a date
+/// eg: +012023-01-05 to (12023, -01-05) or -000044-03-15 to (-44, -03-15)
+pub(crate) fn split_expanded_year(s: &str) -> Option<(i32, &str)> {
+    let (year, rest) = (s.get(..7)?, &s[7..]);
+    if !year.starts_with(['+', '-'])
+        || !year.as_bytes()[1..].iter().all(u8::is_ascii_digit)
+        || !rest.starts_with('-')
+    {
+        return None;
+    }
+    Some((year.parse().ok()?, rest))
+}
+
+/// Returns the index of a space or a lowercase `t` separating a `YYYY-M(M)-D(D)`
+/// or `±YYYYYY-M(M)-D(D)` date from a time
+fn noncanonical_iso_separator(s: &str) -> Option<usize> {
+    let bytes = s.as_bytes();
+    let digits = |from: usize| {
+        bytes[from..]
+            .iter()
+            .take_while(|b| b.is_ascii_digit())
+            .count()
+    };
+    let year = match split_expanded_year(s) {
+        Some((_, rest)) => s.len() - rest.len(),
+        None if bytes.len() >= 4 && digits(0) == 4 => 4,
+        None => return None,
+    };
+    if bytes.len() < year + 6 || bytes[year] != b'-' {
+        return None;
+    }
+    let month = digits(year + 1);
+    if !(1..=2).contains(&month) || bytes.get(year + 1 + month) != Some(&b'-') {
+        return None;
+    }
+    let idx = year + 2 + month;
+    let day = digits(idx);
+    let idx = idx + day;
+    let separated = (1..=2).contains(&day)
+        && matches!(bytes.get(idx), Some(b't' | b' '))
+        && bytes.get(idx + 1).is_some_and(u8::is_ascii_digit);
+    separated.then_some(idx)
+}
+
+/// Rewrites the space or lowercase `t` separating the date and time of an ISO datetime
+/// to `T`, so the formats only need the `T` form, and upcases the `z` designator
+/// eg: 2023-01-05 07:27:19 or 2023-01-05t07:27:19z to 2023-01-05T07:27:19(Z)
+/// Only the separator right after a `YYYY-MM-DD` date and a `z` right after a digit are
+/// touched, so textual dates, zone names and month abbreviations are left alone
+fn canonicalize_iso_separator(s: &mut String) {
+    let Some(idx) = noncanonical_iso_separator(s) else {
+        return;
+    };
+    s.replace_range(idx..idx + 1, "T");
+    if s.ends_with('z') && s[..s.len() - 1].ends_with(|c: char| c.is_ascii_digit()) {
+        s.pop();
+        s.push('Z');
     }
-    .replace(" UTC", " GMT")
-    .replace(" UT", " GMT")
-    .replace([',', ';'], "")
 }
@@ -0,0 +1,161 @@
+//! Localized month and weekday names
+//!
+//! Inputs in a non-English locale are rewritten token by token into the
+//! English names understood by the chrono format strings, eg:
+//! `5 janvier 2023` to `5 January 2023` or `5. Januar 2023` to `5 January 2023`,
+//! and French times into colon separated ones, eg: `15h30` to `15:30:00`
+use alloc::{borrow::Cow, format, string::ToString, vec::Vec};
+
+/// Locale of the month and weekday names found in the input
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Locale {
+    #[default]
+    English,
+    French,
+    German,
+    Spanish,
+}
+
+const FRENCH: &[(&str, &str)] = &[
+    ("janvier", "January"),
+    ("février", "February"),
+    ("fevrier", "February"),
+    ("mars", "March"),
+    ("avril", "April"),
+    ("mai", "May"),
+    ("juin", "June"),
+    ("juillet", "July"),
+    ("août", "August"),
+    ("aout", "August"),
+    ("septembre", "September"),
+    ("octobre", "October"),
+    ("novembre", "November"),
+    ("décembre", "December"),
+    ("decembre", "December"),
+    ("lundi", "Monday"),
+    ("mardi", "Tuesday"),
+    ("mercredi", "Wednesday"),
+    ("jeudi", "Thursday"),
+    ("vendredi", "Friday"),
+    ("samedi", "Saturday"),
+    ("dimanche", "Sunday"),
+];
+
+const GERMAN: &[(&str, &str)] = &[
+    ("januar", "January"),
+    ("jänner", "January"),
+    ("februar", "February"),
+    ("märz", "March"),
+    ("maerz", "March"),
+    ("april", "April"),
+    ("mai", "May"),
+    ("juni", "June"),
+    ("juli", "July"),
+    ("august", "August"),
+    ("september", "September"),
+    ("oktober", "October"),
+    ("november", "November"),
+    ("dezember", "December"),
+    ("montag", "Monday"),
+    ("dienstag", "Tuesday"),
+    ("mittwoch", "Wednesday"),
+    ("donnerstag", "Thursday"),
+    ("freitag", "Friday"),
+    ("samstag", "Saturday"),
+    ("sonnabend", "Saturday"),
+    ("sonntag", "Sunday"),
+];
+
+const SPANISH: &[(&str, &str)] = &[
+    ("enero", "January"),
+    ("febrero", "February"),
+    ("marzo", "March"),
+    ("abril", "April"),
+    ("mayo", "May"),
+    ("junio", "June"),
+    ("julio", "July"),
+    ("agosto", "August"),
+    ("septiembre", "September"),
+    ("setiembre", "September"),
+    ("octubre", "October"),
+    ("noviembre", "November"),
+    ("diciembre", "December"),
+    ("lunes", "Monday"),
+    ("martes", "Tuesday"),
+    ("miércoles", "Wednesday"),
+    ("miercoles", "Wednesday"),
+    ("jueves", "Thursday"),
+    ("viernes", "Friday"),
+    ("sábado", "Saturday"),
+    ("sabado", "Saturday"),
+    ("domingo", "Sunday"),
+];
+
+impl Locale {
+    fn names(self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            Locale::English => &[],
+            Locale::French => FRENCH,
+            Locale::German => GERMAN,
+            Locale::Spanish => SPANISH,
+        }
+    }
+}
+
+/// Translates the month/weekday names of the given locale into English
+/// eg: `5. Januar 2023` to `5 January 2023` or `5 de enero de 2023` to `5 January 2023`
+pub(crate) fn translate(s: &str, locale: Locale) -> Cow<'_, str> {
+    if locale == Locale::English {
+        return Cow::Borrowed(s);
+    }
+    let names = locale.names();
+    let tokens = s
+        .split(' ')
+        .filter(|token| !(locale == Locale::Spanish && matches!(*token, "de" | "del")))
+        .map(|token| {
+            let word = token.trim_end_matches([',', '.']);
+            let suffix = &token[word.len()..];
+            let lower = word.to_lowercase();
+            if let Some((_, english)) = names.iter().find(|(name, _)| *name == lower) {
+                format!("{}{}", english, suffix)
+            } else if locale == Locale::German
+                && suffix == "."
+                && !word.is_empty()
+                && word.chars().all(|c| c.is_ascii_digit())
+            {
+                // German ordinal day, eg: `5.`
+                word.to_string()
+            } else if locale == Locale::French && lower == "1er" {
+                "1".to_string()
+            } else if let Some(time) = french_time(&lower).filter(|_| locale == Locale::French) {
+                time
+            } else {
+                token.to_string()
+            }
+        })
+        .collect::<Vec<_>>();
+    Cow::Owned(tokens.join(" "))
+}
+
+/// Rewrites a French time into `HH:MM:SS`
+/// eg: `15h30` to `15:30:00`, `9h05` to `09:05:00` or `15h30m20s` to `15:30:20`
+fn french_time(s: &str) -> Option<String> {
+    let number = |s: &str| {
+        let valid = (1..=2).contains(&s.len()) && s.chars().all(|c| c.is_ascii_digit());
+        valid.then(|| s.parse::<u32>().ok()).flatten()
+    };
+    let (hour, rest) = s.split_once('h')?;
+    let (minute, second) = match rest.split_once('m') {
+        Some((minute, second)) => (minute, second.strip_suffix('s').unwrap_or(second)),
+        None => (rest, "0"),
+    };
+    if minute.len() != 2 {
+        return None;
+    }
+    Some(format!(
+        "{:02}:{:02}:{:02}",
+        number(hour)?,
+        number(minute)?,
+        number(second)?
+    ))
+}
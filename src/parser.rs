@@ -0,0 +1,828 @@
+//! Configurable parser
+//!
+//! `ParserOptions` holds the knobs that change how an input is interpreted,
+//! and `Parser` is the entry point that applies them.
+use alloc::{
+    borrow::Cow,
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+#[cfg(feature = "std")]
+use std::io::BufRead;
+
+use chrono::{
+    DateTime, Datelike, Duration, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, TimeZone,
+};
+#[cfg(feature = "std")]
+use chrono::{Local, LocalResult, Utc};
+
+#[cfg(feature = "locale")]
+use crate::locale::{self, Locale};
+#[cfg(feature = "std")]
+use crate::trace;
+use crate::{
+    offset_origin, parse_components_from, parse_from, parse_interval_from, parse_range_from,
+    parse_unbounded, parse_with_formats_from, pipeline, warnings, DateTimeFixedOffset,
+    OffsetOrigin, ParseError, ParsedVia, Warning,
+};
+
+/// Order of the day and month in numeric dates such as `05-01-23`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum DateOrder {
+    /// Month first, eg: `05-01-23` is May 1st 2023
+    #[default]
+    Mdy,
+    /// Day first, eg: `05.01.23` is January 5th 2023
+    Dmy,
+}
+
+/// Numbering of the weeks of a year, eg: in `Week 5 2023` or `2023-W05`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum WeekNumbering {
+    /// ISO 8601, where weeks start on Monday and week 1 holds the first Thursday
+    #[default]
+    Iso,
+    /// US, where weeks start on Sunday and week 1 holds January 1st
+    Us,
+}
+
+/// Resolution of a local time that occurs twice, when the clocks go back
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Ambiguous {
+    /// The earlier of the two instants, ie: the one before the clocks go back
+    #[default]
+    Earliest,
+    /// The later of the two instants, ie: the one after the clocks go back
+    Latest,
+    /// Fail with `ParseError::AmbiguousLocalTime`
+    Reject,
+}
+
+/// Day zero of spreadsheet serial date numbers
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ExcelEpoch {
+    /// Windows Excel, where 1 is 1900-01-01 and the nonexistent 1900-02-29 is serial 60
+    Epoch1900,
+    /// Excel for Mac before 2011, where 0 is 1904-01-01
+    Epoch1904,
+}
+
+/// ParserOptions controls how date/time strings are interpreted.
+/// The default options behave exactly like `str::parse::<DateTimeFixedOffset>()`.
+#[derive(Debug, Clone)]
+pub struct ParserOptions {
+    /// Language of month and weekday names in the input (defaults to English)
+    #[cfg(feature = "locale")]
+    pub locale: Locale,
+    /// Treat a bare 4-digit number as a unix timestamp (the default) rather than
+    /// a year. When `false`, `2023` parses as January 1st 2023 at midnight.
+    pub reject_year_only: bool,
+    /// Reference instant for relative inputs (eg: `+1d 09:00`) and for the date or year
+    /// missing from an input; the system clock is used when not set.
+    /// Without the `std` feature there is no clock nor timezone database: inputs without
+    /// an offset resolve against the offset of this instant, and fail when it is not set
+    pub now: Option<DateTime<FixedOffset>>,
+    /// Take a date without a year (eg: `Dec 31 14:00`) in the year before the one of `now`
+    /// when it would otherwise fall more than this many days after `now`, so that
+    /// `Dec 31` read on January 2nd is two days ago rather than next year (off by default)
+    pub max_future_days: Option<u32>,
+    /// Year of a date without one (eg: `Dec 31 14:00`) instead of the year of `now`,
+    /// eg: when backfilling old logs. `max_future_days` does not apply to it
+    pub assume_year: Option<i32>,
+    /// Offset that inputs without one are read in, instead of the system timezone.
+    /// Set it (together with `now`) where there is no OS timezone, eg: wasm in the browser.
+    /// The current date of inputs without one is also taken in this offset, so
+    /// `FixedOffset::east_opt(0)` anchors a bare time such as `15:30:00` to today in UTC
+    pub local_offset: Option<FixedOffset>,
+    /// Reject matches whose `ParsedVia::confidence` is below this threshold
+    /// with `ParseError::LowConfidence` (defaults to 0.0, accepting every match)
+    pub min_confidence: f32,
+    /// Order of the day and month in numeric dates (defaults to month first)
+    pub date_order: DateOrder,
+    /// Fail with `ParseError::Ambiguous` when a numeric date reads as different dates
+    /// in either order, eg: `01-02-03`, instead of taking the one of `date_order` (off by default)
+    pub strict_ambiguity: bool,
+    /// Earliest year that a two-digit year in a numeric date stands for, the others
+    /// following it up to 99 years later, eg: from 1950 `05-06-49` is in 2049 and
+    /// `05-06-50` in 1950 (defaults to 1970 like chrono's `%y`, so `69` is 2069)
+    pub two_digit_year_pivot: u16,
+    /// Numbering of the weeks in week-of-year inputs, which resolve to the Monday
+    /// of the week (defaults to ISO 8601)
+    pub week_numbering: WeekNumbering,
+    /// Offset every result is converted to, preserving the instant; when not set
+    /// the result keeps the offset found in (or assumed for) the input
+    pub output_timezone: Option<FixedOffset>,
+    /// Offset that unix timestamps and .NET dates without an offset are expressed in,
+    /// preserving the instant. They are UTC instants, and are otherwise returned in UTC
+    /// whatever `local_offset` is; `output_timezone` still takes precedence
+    pub timestamp_target_offset: Option<FixedOffset>,
+    /// Best effort mode for lenient ingestion: instead of failing, salvage the input by
+    /// - clamping a day past the end of its month to the last day (`2023-02-30` to `2023-02-28`)
+    /// - clamping a month above 12 to December
+    /// - clamping an hour above 23, a minute above 59 and a second above 60 to 23, 59 and 59
+    /// - pushing a local time that falls in a DST gap forward by the length of the gap
+    pub coerce_invalid: bool,
+    /// Resolution of a local time that occurs twice in the system timezone
+    /// (defaults to the earlier instant)
+    pub ambiguous: Ambiguous,
+    /// Skip blank lines in `Parser::parse_lines` and blank elements in `Parser::parse_list`
+    /// (the default) instead of returning `ParseError::Empty` for them
+    pub skip_blank_lines: bool,
+    /// Read bare numbers as spreadsheet serial dates in the given system, eg: `44927.3107`,
+    /// before trying them as unix timestamps (off by default)
+    pub excel_epoch: Option<ExcelEpoch>,
+    /// Fail `Parser::parse_range` with `ParseError::ReversedRange` when the end
+    /// precedes the start (off by default)
+    pub reject_reversed_range: bool,
+    /// Read a bare 4-digit number as a 24-hour `HHMM` time of the current date,
+    /// eg: `1430`, rather than as a unix timestamp (off by default).
+    /// With a zone, eg: `1430Z`, it is always read as a time.
+    /// A year takes precedence when `reject_year_only` is disabled
+    pub compact_time: bool,
+    /// Earliest instant accepted, an earlier result fails with `ParseError::OutOfRange`,
+    /// eg: to reject dates before 1970 (off by default)
+    pub min: Option<DateTime<FixedOffset>>,
+    /// Latest instant accepted, a later result fails with `ParseError::OutOfRange`,
+    /// eg: to catch a timestamp in milliseconds read as seconds (off by default)
+    pub max: Option<DateTime<FixedOffset>>,
+    /// Longest input in bytes, longer ones fail early with `ParseError::TooLong`
+    /// to bound the cost of untrusted input (defaults to 256)
+    pub max_input_len: usize,
+    /// Most whitespace separated tokens in an input, more fail early with
+    /// `ParseError::TooLong` (defaults to 16)
+    pub max_tokens: usize,
+}
+
+impl Default for ParserOptions {
+    fn default() -> Self {
+        ParserOptions {
+            #[cfg(feature = "locale")]
+            locale: Locale::default(),
+            reject_year_only: true,
+            now: None,
+            max_future_days: None,
+            assume_year: None,
+            local_offset: None,
+            min_confidence: 0.0,
+            date_order: DateOrder::default(),
+            strict_ambiguity: false,
+            two_digit_year_pivot: 1970,
+            week_numbering: WeekNumbering::default(),
+            output_timezone: None,
+            timestamp_target_offset: None,
+            coerce_invalid: false,
+            ambiguous: Ambiguous::default(),
+            skip_blank_lines: true,
+            excel_epoch: None,
+            reject_reversed_range: false,
+            compact_time: false,
+            min: None,
+            max: None,
+            max_input_len: 256,
+            max_tokens: 16,
+        }
+    }
+}
+
+impl ParserOptions {
+    /// Rewrites localized month/weekday names into the English names the formats expect
+    pub(crate) fn localize<'a>(&self, s: &'a str) -> Cow<'a, str> {
+        #[cfg(feature = "locale")]
+        return locale::translate(s, self.locale);
+        #[cfg(not(feature = "locale"))]
+        Cow::Borrowed(s)
+    }
+
+    /// Returns the reference instant in the local offset, falling back to the system clock
+    #[cfg(feature = "std")]
+    pub(crate) fn now(&self) -> Result<DateTime<FixedOffset>, String> {
+        let now = self.now.unwrap_or_else(|| match self.local_offset {
+            Some(_) => Utc::now().fixed_offset(),
+            None => Local::now().fixed_offset(),
+        });
+        Ok(self.in_local_offset(now))
+    }
+
+    /// Returns the reference instant in the local offset, which has to be set
+    /// without a system clock
+    #[cfg(not(feature = "std"))]
+    pub(crate) fn now(&self) -> Result<DateTime<FixedOffset>, String> {
+        self.now
+            .map(|now| self.in_local_offset(now))
+            .ok_or_else(|| "no system clock without std, set ParserOptions.now".to_string())
+    }
+
+    /// Converts an instant to `local_offset` when set, so that the current date
+    /// is the one in that offset
+    fn in_local_offset(&self, now: DateTime<FixedOffset>) -> DateTime<FixedOffset> {
+        match self.local_offset {
+            Some(offset) => now.with_timezone(&offset),
+            None => now,
+        }
+    }
+
+    /// Year of a date without one: `assume_year`, or else the year of `now`
+    pub(crate) fn year(&self) -> Result<i32, String> {
+        match self.assume_year {
+            Some(year) => Ok(year),
+            None => Ok(self.now()?.year()),
+        }
+    }
+
+    /// Year that a two-digit year stands for, see `two_digit_year_pivot`
+    pub(crate) fn two_digit_year(&self, yy: u32) -> i32 {
+        let pivot = i32::from(self.two_digit_year_pivot);
+        let year = pivot - pivot % 100 + (yy % 100) as i32;
+        if year < pivot {
+            year + 100
+        } else {
+            year
+        }
+    }
+
+    /// Fails with `ParseError::OutOfRange` when the instant is before `min` or after `max`
+    pub(crate) fn within_bounds(
+        &self,
+        dt: DateTime<FixedOffset>,
+    ) -> Result<DateTime<FixedOffset>, ParseError> {
+        let before = self.min.is_some_and(|min| dt < min);
+        let after = self.max.is_some_and(|max| dt > max);
+        if before || after {
+            return Err(ParseError::OutOfRange {
+                component: "date",
+                value: dt.to_rfc3339(),
+            });
+        }
+        Ok(dt)
+    }
+
+    /// Fails with `ParseError::OutOfRange` when the wall-clock date/time is before the
+    /// wall-clock time of `min` or after that of `max`
+    pub(crate) fn within_naive_bounds(
+        &self,
+        x: NaiveDateTime,
+    ) -> Result<NaiveDateTime, ParseError> {
+        let before = self.min.is_some_and(|min| x < min.naive_local());
+        let after = self.max.is_some_and(|max| x > max.naive_local());
+        if before || after {
+            return Err(ParseError::OutOfRange {
+                component: "date",
+                value: x.to_string(),
+            });
+        }
+        Ok(x)
+    }
+
+    /// Moves a date/time whose year was taken from `now` back a year when it
+    /// falls more than `max_future_days` after `now`
+    pub(crate) fn recent_year(&self, x: NaiveDateTime) -> Result<NaiveDateTime, String> {
+        let days = match self.max_future_days {
+            Some(days) if self.assume_year.is_none() => days,
+            _ => return Ok(x),
+        };
+        if x - self.now()?.naive_local() <= Duration::days(days.into()) {
+            return Ok(x);
+        }
+        // February 29th has no counterpart in the year before
+        Ok(x.with_year(x.year() - 1).unwrap_or(x))
+    }
+
+    /// Resolves a local date/time against the system timezone. An ambiguous time
+    /// (when the clocks go back) resolves as set by `ambiguous`; a time skipped by
+    /// the clocks going forward is an error unless `coerce_invalid` is set.
+    /// A `local_offset` replaces the system timezone.
+    #[cfg(feature = "std")]
+    pub(crate) fn local(&self, x: &NaiveDateTime) -> Result<DateTime<FixedOffset>, String> {
+        if let Some(offset) = self.local_offset {
+            return fixed_local(offset, x);
+        }
+        match Local.from_local_datetime(x) {
+            LocalResult::Single(dt) => return Ok(dt.fixed_offset()),
+            LocalResult::Ambiguous(a, b) => return self.resolve_ambiguous(x, a, b),
+            LocalResult::None => {}
+        }
+        if !self.coerce_invalid {
+            return Err(format!("{} does not exist in the local timezone", x));
+        }
+        skip_gap(x).ok_or_else(|| format!("{} does not exist in the local timezone", x))
+    }
+
+    /// Resolves the start of a local day. Unlike a time of day, a date alone always
+    /// has a start: when the clocks go forward at midnight (eg: Brazil until 2019)
+    /// the day starts at the first instant after the gap, whatever `coerce_invalid` is.
+    #[cfg(feature = "std")]
+    pub(crate) fn start_of_day(&self, date: NaiveDate) -> Result<DateTime<FixedOffset>, String> {
+        let midnight = date.and_time(NaiveTime::MIN);
+        if self.local_offset.is_some() {
+            return self.local(&midnight);
+        }
+        match Local.from_local_datetime(&midnight) {
+            LocalResult::None => skip_gap(&midnight)
+                .ok_or_else(|| format!("{} does not exist in the local timezone", midnight)),
+            // chrono reads the first instant of a gap with the offset before it,
+            // eg: 00:00-03:00 rather than 01:00-02:00
+            _ => self
+                .local(&midnight)
+                .map(|dt| Local.from_utc_datetime(&dt.naive_utc()).fixed_offset()),
+        }
+    }
+
+    /// Picks one of the two instants of a local time that occurs twice as set by `ambiguous`
+    #[cfg(feature = "std")]
+    pub(crate) fn resolve_ambiguous<Tz: TimeZone>(
+        &self,
+        x: &NaiveDateTime,
+        a: DateTime<Tz>,
+        b: DateTime<Tz>,
+    ) -> Result<DateTime<FixedOffset>, String> {
+        // the pair is not ordered by instant
+        match self.ambiguous {
+            Ambiguous::Earliest => Ok(a.min(b).fixed_offset()),
+            Ambiguous::Latest => Ok(a.max(b).fixed_offset()),
+            Ambiguous::Reject => Err(format!("{} is ambiguous in the local timezone", x)),
+        }
+    }
+
+    /// Resolves a local date/time against `local_offset`, or else the offset of the
+    /// reference instant, as there is no system timezone without std
+    #[cfg(not(feature = "std"))]
+    pub(crate) fn local(&self, x: &NaiveDateTime) -> Result<DateTime<FixedOffset>, String> {
+        match self.local_offset {
+            Some(offset) => fixed_local(offset, x),
+            None => fixed_local(*self.now()?.offset(), x),
+        }
+    }
+
+    /// Resolves the start of a local day against `local_offset` or the offset of `now`
+    #[cfg(not(feature = "std"))]
+    pub(crate) fn start_of_day(&self, date: NaiveDate) -> Result<DateTime<FixedOffset>, String> {
+        self.local(&date.and_time(NaiveTime::MIN))
+    }
+}
+
+/// Moves a local date/time skipped by the clocks going forward past the gap,
+/// eg: 02:30 becomes 03:30 when the clocks go from 02:00 to 03:00
+#[cfg(feature = "std")]
+fn skip_gap(x: &NaiveDateTime) -> Option<DateTime<FixedOffset>> {
+    let offset = |x: NaiveDateTime| {
+        Local
+            .from_local_datetime(&x)
+            .earliest()
+            .map(|dt| dt.offset().local_minus_utc() as i64)
+    };
+    let gap = offset(*x + Duration::days(1)).zip(offset(*x - Duration::days(1)));
+    gap.and_then(|(after, before)| {
+        Local
+            .from_local_datetime(&(*x + Duration::seconds(after - before)))
+            .earliest()
+    })
+    .map(|dt| dt.fixed_offset())
+}
+
+/// Reads a local date/time in a fixed offset
+fn fixed_local(offset: FixedOffset, x: &NaiveDateTime) -> Result<DateTime<FixedOffset>, String> {
+    offset
+        .from_local_datetime(x)
+        .single()
+        .ok_or_else(|| format!("{} is out of range", x))
+}
+
+/// Parser parses date/time strings using the given `ParserOptions`.
+///
+/// ## Example usage:
+/// ```
+/// use datetime_parse::Parser;
+///
+/// let parser = Parser::new();
+/// let result = parser.parse("Mon, 6 Jul 1970 15:30:00 PDT");
+/// assert!(result.is_ok());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Parser {
+    options: ParserOptions,
+}
+
+impl Parser {
+    /// Creates a parser with the default options
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a parser with the given options
+    pub fn with_options(options: ParserOptions) -> Self {
+        Parser { options }
+    }
+
+    /// Returns the options this parser was configured with
+    pub fn options(&self) -> &ParserOptions {
+        &self.options
+    }
+
+    /// Sets the language of month and weekday names in the input
+    #[cfg(feature = "locale")]
+    pub fn locale(mut self, locale: Locale) -> Self {
+        self.options.locale = locale;
+        self
+    }
+
+    /// Sets whether a bare 4-digit number is a unix timestamp (`true`) or a year (`false`)
+    pub fn reject_year_only(mut self, reject: bool) -> Self {
+        self.options.reject_year_only = reject;
+        self
+    }
+
+    /// Sets the reference instant used to resolve relative inputs
+    pub fn now(mut self, now: DateTime<FixedOffset>) -> Self {
+        self.options.now = Some(now);
+        self
+    }
+
+    /// Sets how many days after `now` a date without a year may fall before it is
+    /// taken in the previous year, see `ParserOptions.max_future_days`
+    pub fn max_future_days(mut self, days: u32) -> Self {
+        self.options.max_future_days = Some(days);
+        self
+    }
+
+    /// Sets the year of dates without one, see `ParserOptions.assume_year`
+    pub fn assume_year(mut self, year: i32) -> Self {
+        self.options.assume_year = Some(year);
+        self
+    }
+
+    /// Sets the offset inputs without one are read in, replacing the system timezone
+    ///
+    /// ## Example usage:
+    /// ```
+    /// use chrono::{DateTime, FixedOffset};
+    /// use datetime_parse::Parser;
+    ///
+    /// // in the browser, from `new Date()` and `getTimezoneOffset()` (minutes west of UTC)
+    /// let (now_ms, timezone_offset) = (1672903639123_i64, -330);
+    /// let offset = FixedOffset::west_opt(timezone_offset * 60).unwrap();
+    /// let now = DateTime::from_timestamp_millis(now_ms).unwrap().with_timezone(&offset);
+    /// let parser = Parser::new().now(now).local_offset(offset);
+    /// let parsed = parser.parse("09:00:00").unwrap();
+    /// assert_eq!(parsed.to_string(), "2023-01-05T09:00:00+05:30");
+    /// ```
+    pub fn local_offset(mut self, offset: FixedOffset) -> Self {
+        self.options.local_offset = Some(offset);
+        self
+    }
+
+    /// Sets the confidence below which a successful match is rejected
+    pub fn min_confidence(mut self, min_confidence: f32) -> Self {
+        self.options.min_confidence = min_confidence;
+        self
+    }
+
+    /// Sets the order of the day and month in numeric dates
+    pub fn date_order(mut self, date_order: DateOrder) -> Self {
+        self.options.date_order = date_order;
+        self
+    }
+
+    /// Sets whether a numeric date that reads as different dates in either order
+    /// fails with `ParseError::Ambiguous`
+    pub fn strict_ambiguity(mut self, strict: bool) -> Self {
+        self.options.strict_ambiguity = strict;
+        self
+    }
+
+    /// Sets the earliest year that a two-digit year stands for, see
+    /// `ParserOptions.two_digit_year_pivot`
+    pub fn two_digit_year_pivot(mut self, pivot: u16) -> Self {
+        self.options.two_digit_year_pivot = pivot;
+        self
+    }
+
+    /// Sets the numbering of the weeks in week-of-year inputs such as `Week 5 2023`
+    pub fn week_numbering(mut self, week_numbering: WeekNumbering) -> Self {
+        self.options.week_numbering = week_numbering;
+        self
+    }
+
+    /// Sets the offset every result is displayed in, eg: to report everything in UTC
+    pub fn output_timezone(mut self, output_timezone: FixedOffset) -> Self {
+        self.options.output_timezone = Some(output_timezone);
+        self
+    }
+
+    /// Sets the offset timestamps are expressed in, see `ParserOptions.timestamp_target_offset`
+    pub fn timestamp_target_offset(mut self, offset: FixedOffset) -> Self {
+        self.options.timestamp_target_offset = Some(offset);
+        self
+    }
+
+    /// Returns the branches this configuration tries, in the order they are tried
+    ///
+    /// ## Example usage:
+    /// ```
+    /// use datetime_parse::{ParsedVia, Parser};
+    ///
+    /// assert!(!Parser::new().pipeline().contains(&ParsedVia::YearOnly));
+    /// assert!(Parser::new().reject_year_only(false).pipeline().contains(&ParsedVia::YearOnly));
+    /// ```
+    pub fn pipeline(&self) -> Vec<ParsedVia> {
+        pipeline(&self.options).collect()
+    }
+
+    /// Salvages invalid inputs instead of failing, see `ParserOptions.coerce_invalid`
+    /// for the coercions this enables
+    pub fn coerce_invalid_to_nearest_valid(mut self, coerce: bool) -> Self {
+        self.options.coerce_invalid = coerce;
+        self
+    }
+
+    /// Sets how a local time that occurs twice is resolved, see `ParserOptions.ambiguous`
+    pub fn ambiguous(mut self, policy: Ambiguous) -> Self {
+        self.options.ambiguous = policy;
+        self
+    }
+
+    /// Sets whether `parse_lines` skips blank lines or reports them as `ParseError::Empty`
+    pub fn skip_blank_lines(mut self, skip: bool) -> Self {
+        self.options.skip_blank_lines = skip;
+        self
+    }
+
+    /// Reads bare numbers as spreadsheet serial dates in the given system
+    pub fn excel_epoch(mut self, epoch: ExcelEpoch) -> Self {
+        self.options.excel_epoch = Some(epoch);
+        self
+    }
+
+    /// Sets whether `parse_range` fails when the end of the range precedes its start
+    pub fn reject_reversed_range(mut self, reject: bool) -> Self {
+        self.options.reject_reversed_range = reject;
+        self
+    }
+
+    /// Reads a bare 4-digit number as a `HHMM` time, see `ParserOptions.compact_time`
+    pub fn compact_time(mut self, compact: bool) -> Self {
+        self.options.compact_time = compact;
+        self
+    }
+
+    /// Sets the earliest instant accepted, see `ParserOptions.min`
+    pub fn min(mut self, min: DateTime<FixedOffset>) -> Self {
+        self.options.min = Some(min);
+        self
+    }
+
+    /// Sets the latest instant accepted, see `ParserOptions.max`
+    pub fn max(mut self, max: DateTime<FixedOffset>) -> Self {
+        self.options.max = Some(max);
+        self
+    }
+
+    /// Sets the longest input in bytes, see `ParserOptions.max_input_len`
+    pub fn max_input_len(mut self, max: usize) -> Self {
+        self.options.max_input_len = max;
+        self
+    }
+
+    /// Sets the most whitespace separated tokens in an input, see `ParserOptions.max_tokens`
+    pub fn max_tokens(mut self, max: usize) -> Self {
+        self.options.max_tokens = max;
+        self
+    }
+
+    /// Parses the date/time string with the configured options
+    pub fn parse(&self, s: &str) -> Result<DateTimeFixedOffset, ParseError> {
+        parse_from(s, &self.options).map(|(dt, _)| DateTimeFixedOffset(dt))
+    }
+
+    /// Parses the date/time string and keeps only its date, taken in the offset of
+    /// the result, see `parse_date_only`. With `output_timezone` set that is the
+    /// date in the output timezone.
+    pub fn parse_date_only(&self, s: &str) -> Result<NaiveDate, ParseError> {
+        self.parse(s).map(|dt| dt.0.date_naive())
+    }
+
+    /// Parses the date/time string and keeps only its time of day, taken in the offset
+    /// of the result, see `parse_time_only`
+    pub fn parse_time_only(&self, s: &str) -> Result<NaiveTime, ParseError> {
+        self.parse(s).map(|dt| dt.0.time())
+    }
+
+    /// Parses the date/time string into its wall-clock date and time, see `parse_naive`.
+    /// `output_timezone` does not apply, as the result has no offset to convert from.
+    /// `min` and `max` bound the instant of an input with an offset, and the wall-clock
+    /// time of one without, which is compared with their own wall-clock time.
+    pub fn parse_naive(&self, s: &str) -> Result<NaiveDateTime, ParseError> {
+        // a fixed offset keeps every wall-clock time as written, even one that the
+        // system timezone skips, while the current date stays the local one
+        let offset = self
+            .options
+            .local_offset
+            .or_else(|| self.options.now().ok().map(|now| *now.offset()))
+            .unwrap_or(FixedOffset::east_opt(0).unwrap());
+        let options = ParserOptions {
+            local_offset: Some(offset),
+            output_timezone: None,
+            ..self.options.clone()
+        };
+        let (dt, via) = parse_unbounded(s, &options)?;
+        match offset_origin(s, via) {
+            OffsetOrigin::Inferred => options.within_naive_bounds(dt.naive_local()),
+            _ => options.within_bounds(dt).map(|dt| dt.naive_local()),
+        }
+    }
+
+    /// Checks whether the date/time string parses with the configured options
+    pub fn is_valid(&self, s: &str) -> bool {
+        parse_from(s, &self.options).is_ok()
+    }
+
+    /// Parses the date/time string, returning `default` when it cannot be parsed,
+    /// see `parse_or`
+    pub fn parse_or(&self, s: &str, default: DateTime<FixedOffset>) -> DateTime<FixedOffset> {
+        self.parse_or_else(s, || default)
+    }
+
+    /// Parses the date/time string, returning the result of `f` when it cannot be parsed,
+    /// see `parse_or_else`
+    pub fn parse_or_else<F>(&self, s: &str, f: F) -> DateTime<FixedOffset>
+    where
+        F: FnOnce() -> DateTime<FixedOffset>,
+    {
+        self.parse(s).map(|dt| dt.0).unwrap_or_else(|_| f())
+    }
+
+    /// Parses the date/time string with the given chrono format strings only,
+    /// see `parse_with_formats`
+    pub fn parse_with_formats(
+        &self,
+        s: &str,
+        formats: &[&str],
+    ) -> Result<DateTimeFixedOffset, ParseError> {
+        parse_with_formats_from(s, formats, &self.options).map(DateTimeFixedOffset)
+    }
+
+    /// Parses both ends of a date range, see `parse_range`
+    pub fn parse_range(
+        &self,
+        s: &str,
+    ) -> Result<(DateTimeFixedOffset, DateTimeFixedOffset), ParseError> {
+        parse_range_from(s, &self.options)
+            .map(|(start, end)| (DateTimeFixedOffset(start), DateTimeFixedOffset(end)))
+    }
+
+    /// Parses both ends of an ISO 8601 interval, see `parse_interval`
+    pub fn parse_interval(
+        &self,
+        s: &str,
+    ) -> Result<(DateTimeFixedOffset, DateTimeFixedOffset), ParseError> {
+        parse_interval_from(s, &self.options)
+            .map(|(start, end)| (DateTimeFixedOffset(start), DateTimeFixedOffset(end)))
+    }
+
+    /// Parses a date, time and timezone that were already split apart, see `parse_components`
+    pub fn parse_components(
+        &self,
+        date: &str,
+        time: Option<&str>,
+        tz: Option<&str>,
+    ) -> Result<DateTime<FixedOffset>, ParseError> {
+        parse_components_from(date, time, tz, &self.options)
+    }
+
+    /// Parses every item, keeping the results in input order
+    pub fn parse_many<I>(&self, items: I) -> Vec<Result<DateTimeFixedOffset, ParseError>>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        items
+            .into_iter()
+            .map(|item| self.parse(item.as_ref()))
+            .collect()
+    }
+
+    /// Parses every item and returns only the successes, each with the index of its item
+    pub fn parse_many_ok<I>(&self, items: I) -> Vec<(usize, DateTimeFixedOffset)>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        items
+            .into_iter()
+            .enumerate()
+            .filter_map(|(i, item)| self.parse(item.as_ref()).ok().map(|dt| (i, dt)))
+            .collect()
+    }
+
+    /// Splits the string on `sep` and parses every element, see `parse_list`
+    pub fn parse_list(
+        &self,
+        s: &str,
+        sep: &str,
+    ) -> Result<Vec<DateTimeFixedOffset>, Vec<(usize, ParseError)>> {
+        let mut parsed = Vec::new();
+        let mut errors = Vec::new();
+        for (i, item) in s.split(sep).enumerate() {
+            if item.trim().is_empty() && self.options.skip_blank_lines {
+                continue;
+            }
+            match self.parse(item.trim()) {
+                Ok(dt) => parsed.push(dt),
+                Err(e) => errors.push((i, e)),
+            }
+        }
+        if errors.is_empty() {
+            Ok(parsed)
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Lazily parses one date/time per line, yielding each result with its 1-based line number.
+    /// A line that cannot be read is returned as `ParseError::Io`.
+    ///
+    /// ## Example usage:
+    /// ```
+    /// use std::io::Cursor;
+    /// use datetime_parse::Parser;
+    ///
+    /// let lines = Cursor::new("2023-01-05\n\nnot a date\n");
+    /// let results = Parser::new().parse_lines(lines).collect::<Vec<_>>();
+    /// assert_eq!(results.len(), 2);
+    /// assert_eq!(results[1].0, 3);
+    /// assert!(results[1].1.is_err());
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn parse_lines<R: BufRead>(
+        &self,
+        reader: R,
+    ) -> impl Iterator<Item = (usize, Result<DateTimeFixedOffset, ParseError>)> {
+        let parser = self.clone();
+        reader
+            .lines()
+            .enumerate()
+            .filter_map(move |(i, line)| match line {
+                Ok(line) if line.trim().is_empty() && parser.options.skip_blank_lines => None,
+                Ok(line) => Some((i + 1, parser.parse(line.trim()))),
+                Err(e) => Some((i + 1, Err(ParseError::Io(e.to_string())))),
+            })
+    }
+
+    /// Returns the parser as a plain function value, eg: for use in combinators
+    ///
+    /// ## Example usage:
+    /// ```
+    /// use chrono::FixedOffset;
+    /// use datetime_parse::{DateOrder, Parser};
+    ///
+    /// let parse = Parser::new()
+    ///     .date_order(DateOrder::Dmy)
+    ///     .local_offset(FixedOffset::east_opt(0).unwrap())
+    ///     .as_fn();
+    /// let dates = ["05.01.23", "2023-01-05"].map(|x| parse(x).unwrap().0.date_naive());
+    /// assert_eq!(dates[0], dates[1]);
+    /// ```
+    pub fn as_fn(&self) -> impl Fn(&str) -> Result<DateTimeFixedOffset, ParseError> {
+        let parser = self.clone();
+        move |s| parser.parse(s)
+    }
+
+    /// Parses the date/time string, and on failure returns every format pattern and
+    /// parser branch that was tried, each with the error it failed with
+    #[cfg(feature = "std")]
+    pub fn parse_verbose(&self, s: &str) -> Result<DateTimeFixedOffset, Vec<(String, String)>> {
+        match trace::collect(|| self.parse(s)) {
+            (Ok(dt), _) => Ok(dt),
+            (Err(e), attempts) if attempts.is_empty() => Err(vec![(String::new(), e.to_string())]),
+            (Err(_), attempts) => Err(attempts),
+        }
+    }
+
+    /// Parses the date/time string and also returns the parser branch that matched
+    pub fn parse_with_source(
+        &self,
+        s: &str,
+    ) -> Result<(DateTimeFixedOffset, ParsedVia), ParseError> {
+        parse_from(s, &self.options).map(|(dt, via)| (DateTimeFixedOffset(dt), via))
+    }
+
+    /// Parses the date/time string and also returns where its offset comes from,
+    /// see `parse_with_origin`
+    pub fn parse_with_origin(
+        &self,
+        s: &str,
+    ) -> Result<(DateTimeFixedOffset, OffsetOrigin), ParseError> {
+        parse_from(s, &self.options)
+            .map(|(dt, via)| (DateTimeFixedOffset(dt), offset_origin(s, via)))
+    }
+
+    /// Parses the date/time string and also lists what was guessed along the way,
+    /// see `parse_best_effort`
+    pub fn parse_best_effort(
+        &self,
+        s: &str,
+    ) -> Result<(DateTimeFixedOffset, Vec<Warning>), ParseError> {
+        parse_from(s, &self.options)
+            .map(|(dt, via)| (DateTimeFixedOffset(dt), warnings(s, via, &dt)))
+    }
+}
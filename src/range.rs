@@ -0,0 +1,226 @@
+//! Out of range components
+//!
+//! When no format matches, the input is checked for a recognizable date/time
+//! shape carrying an impossible value (month 13, February 30th, hour 25...)
+//! so the error can name the offending component instead of the format.
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use chrono::NaiveDate;
+
+use crate::{DateOrder, ParseError, ParserOptions};
+
+const MONTHS: [&str; 12] = [
+    "jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec",
+];
+
+/// Returns the `ParseError::OutOfRange` for the first impossible component found
+pub(crate) fn out_of_range(s: &str, options: &ParserOptions) -> Option<ParseError> {
+    // a number too large (or not finite) to be a timestamp, eg: 1e999
+    if let Ok(x) = s.parse::<f64>() {
+        if !x.is_finite() || x <= -(i64::MAX as f64) || x >= i64::MAX as f64 {
+            return Some(ParseError::OutOfRange {
+                component: "timestamp",
+                value: s.to_string(),
+            });
+        }
+    }
+    if let Some((_, quarter)) = crate::split_quarter(s) {
+        if !(1..=4).contains(&quarter) {
+            return Some(error("quarter", quarter));
+        }
+    }
+    if let Some((year, week)) = crate::split_week(s) {
+        if crate::week_monday(year, week, options.week_numbering).is_none() {
+            return Some(error("week", week));
+        }
+    }
+    let (date, rest) = s.split_once(['T', ' ']).unwrap_or((s, ""));
+    let (ymd, time) = match numeric_date(date, options) {
+        Some(ymd) => (Some(ymd), rest),
+        None => (textual_date(s), s),
+    };
+    if let Some((year, month, day)) = ymd {
+        if NaiveDate::from_ymd_opt(year, 1, 1).is_none() {
+            return Some(ParseError::OutOfRange {
+                component: "year",
+                value: year.to_string(),
+            });
+        }
+        if !(1..=12).contains(&month) {
+            return Some(error("month", month));
+        }
+        if day < 1 || day > days_in_month(year, month) {
+            return Some(error("day", day));
+        }
+    }
+    time.split_whitespace()
+        .find_map(|token| out_of_range_time(token.trim_end_matches(['Z', 'z'])))
+}
+
+/// Rewrites the impossible components of a numeric date and of the times to the nearest
+/// valid value, eg: `2023-02-30 25:61` to `2023-02-28 23:59`.
+/// Returns `None` when there was nothing to clamp.
+pub(crate) fn clamp(s: &str, options: &ParserOptions) -> Option<String> {
+    let (date, rest) = match s.find(['T', ' ']) {
+        Some(idx) => s.split_at(idx),
+        None => (s, ""),
+    };
+    let date = match numeric_date(date, options) {
+        Some((year, month, day)) => {
+            let month = month.clamp(1, 12);
+            let day = day.clamp(1, days_in_month(year, month));
+            if crate::split_expanded_year(date).is_some() {
+                // an expanded year out of range is not clamped, see `out_of_range`
+                format!("{}-{:02}-{:02}", &date[..7], month, day)
+            } else {
+                let parts = date.split('-').collect::<Vec<_>>();
+                let values = if parts[0].len() == 4 {
+                    [year as u32, month, day]
+                } else {
+                    match options.date_order {
+                        DateOrder::Mdy => [month, day, parts[2].parse().unwrap_or_default()],
+                        DateOrder::Dmy => [day, month, parts[2].parse().unwrap_or_default()],
+                    }
+                };
+                parts
+                    .iter()
+                    .zip(values)
+                    .map(|(part, value)| format!("{:0width$}", value, width = part.len()))
+                    .collect::<Vec<_>>()
+                    .join("-")
+            }
+        }
+        None => date.to_string(),
+    };
+    let rest = rest
+        .split(' ')
+        .map(|token| {
+            let time = token.trim_start_matches('T').trim_end_matches(['Z', 'z']);
+            match out_of_range_time(time) {
+                Some(_) => token.replacen(time, &clamp_time(time), 1),
+                None => token.to_string(),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ");
+    let clamped = date + &rest;
+    (clamped != s).then_some(clamped)
+}
+
+/// Clamps the fields of a `HH:MM[:SS[.f]]` token, see `out_of_range_time`
+fn clamp_time(time: &str) -> String {
+    time.split(':')
+        .zip([23, 59, 60])
+        .map(|(field, max)| {
+            let (value, fraction) = match field.find('.') {
+                Some(idx) => field.split_at(idx),
+                None => (field, ""),
+            };
+            match value.parse::<u32>() {
+                // a second past the leap second lands on :59
+                Ok(x) if x > max => format!("{:02}{}", max.min(59), fraction),
+                _ => field.to_string(),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(":")
+}
+
+/// Reads `2023-02-30`, `+012023-02-30`, `02-30-2023` or `30-02-2023` into (year, month, day)
+fn numeric_date(date: &str, options: &ParserOptions) -> Option<(i32, u32, u32)> {
+    if let Some((year, rest)) = crate::split_expanded_year(date) {
+        let (month, day) = rest[1..].split_once('-')?;
+        return Some((year, month.parse().ok()?, day.parse().ok()?));
+    }
+    let parts = date.split('-').collect::<Vec<_>>();
+    if parts.len() != 3
+        || parts
+            .iter()
+            .any(|x| x.is_empty() || !x.chars().all(|c| c.is_ascii_digit()))
+    {
+        return None;
+    }
+    let [a, b, c] = [parts[0], parts[1], parts[2]].map(|x| x.parse::<u32>().ok());
+    let (a, b, c) = (a?, b?, c?);
+    if parts[0].len() == 4 {
+        return Some((a as i32, b, c));
+    }
+    let year = if parts[2].len() == 2 {
+        options.two_digit_year(c)
+    } else {
+        c as i32
+    };
+    match options.date_order {
+        DateOrder::Mdy => Some((year, a, b)),
+        DateOrder::Dmy => Some((year, b, a)),
+    }
+}
+
+/// Reads a month name together with a 1-2 digit day and a 4 digit year
+/// eg: Feb 30 2023 or 30 February 2023
+fn textual_date(s: &str) -> Option<(i32, u32, u32)> {
+    let tokens = s.split_whitespace().collect::<Vec<_>>();
+    let month = tokens.iter().find_map(|token| {
+        let token = token.to_lowercase();
+        MONTHS
+            .iter()
+            .position(|m| token.len() >= 3 && token.starts_with(m))
+            .map(|x| x as u32 + 1)
+    })?;
+    let numbers = tokens
+        .iter()
+        .filter(|x| x.chars().all(|c| c.is_ascii_digit()))
+        .collect::<Vec<_>>();
+    let day = numbers.iter().find(|x| x.len() <= 2)?.parse().ok()?;
+    let year = numbers.iter().find(|x| x.len() == 4)?.parse().ok()?;
+    Some((year, month, day))
+}
+
+/// Checks a `HH:MM` or `HH:MM:SS[.f]` token for an impossible hour, minute or second
+fn out_of_range_time(token: &str) -> Option<ParseError> {
+    let parts = token.split(':').collect::<Vec<_>>();
+    if !(2..=3).contains(&parts.len()) {
+        return None;
+    }
+    let second = parts
+        .get(2)
+        .map(|x| x.split('.').next().unwrap_or_default());
+    let fields = [Some(parts[0]), Some(parts[1]), second];
+    if fields
+        .iter()
+        .flatten()
+        .any(|x| x.is_empty() || x.len() > 2 || !x.chars().all(|c| c.is_ascii_digit()))
+    {
+        return None;
+    }
+    let value = |x: &str| x.parse::<u32>().unwrap_or_default();
+    if value(parts[0]) > 23 {
+        return Some(error("hour", value(parts[0])));
+    }
+    if value(parts[1]) > 59 {
+        return Some(error("minute", value(parts[1])));
+    }
+    // 60 is a leap second
+    match second.map(value) {
+        Some(x) if x > 60 => Some(error("second", x)),
+        _ => None,
+    }
+}
+
+fn days_in_month(year: i32, month: u32) -> u32 {
+    (28..=31)
+        .rev()
+        .find(|day| NaiveDate::from_ymd_opt(year, month, *day).is_some())
+        .unwrap_or(31)
+}
+
+fn error(component: &'static str, value: u32) -> ParseError {
+    ParseError::OutOfRange {
+        component,
+        value: value.to_string(),
+    }
+}
@@ -127,3 +127,2746 @@ fn test_m_d_yyyy_h_m_s_tz() {
     eprintln!("{}", test.0.to_rfc3339());
     assert!(test.0.to_rfc3339().starts_with("2024-05-02T10:02:16+02:00"));
 }
+
+#[cfg(feature = "locale")]
+#[test]
+fn test_locale_french() {
+    use crate::{Locale, Parser};
+    let date = "5 janvier 2023";
+    let test = Parser::new().locale(Locale::French).parse(date);
+    assert!(test.is_ok());
+    assert!(test
+        .unwrap()
+        .0
+        .to_rfc3339()
+        .starts_with("2023-01-05T00:00:00"));
+}
+
+#[cfg(feature = "locale")]
+#[test]
+fn test_locale_french_time() {
+    use crate::{Locale, Parser};
+    let now = chrono::DateTime::parse_from_rfc3339("2024-01-02T09:00:00+00:00").unwrap();
+    let offset = chrono::FixedOffset::east_opt(0).unwrap();
+    let parser = Parser::new()
+        .locale(Locale::French)
+        .now(now)
+        .local_offset(offset);
+    for (date, expected) in [
+        ("15h30", "2024-01-02T15:30:00+00:00"),
+        ("9h05", "2024-01-02T09:05:00+00:00"),
+        ("15h30m20s", "2024-01-02T15:30:20+00:00"),
+        ("15h30m20", "2024-01-02T15:30:20+00:00"),
+        ("5 janvier 2023 15h30", "2023-01-05T15:30:00+00:00"),
+        ("5 janvier 2023 9h05m07s", "2023-01-05T09:05:07+00:00"),
+        ("2023-01-05 15h30", "2023-01-05T15:30:00+00:00"),
+    ] {
+        let test = parser.parse(date);
+        assert!(test.is_ok(), "{}", date);
+        assert_eq!(test.unwrap().0.to_rfc3339(), expected, "{}", date);
+    }
+    assert!(parser.parse("25h30").is_err());
+    assert!(Parser::new().parse("15h30").is_err());
+}
+
+#[cfg(feature = "locale")]
+#[test]
+fn test_locale_german() {
+    use crate::{Locale, Parser};
+    let date = "5. Januar 2023";
+    let test = Parser::new().locale(Locale::German).parse(date);
+    assert!(test.is_ok());
+    assert!(test
+        .unwrap()
+        .0
+        .to_rfc3339()
+        .starts_with("2023-01-05T00:00:00"));
+}
+
+#[cfg(feature = "locale")]
+#[test]
+fn test_locale_spanish() {
+    use crate::{Locale, Parser};
+    let date = "5 de enero de 2023";
+    let test = Parser::new().locale(Locale::Spanish).parse(date);
+    assert!(test.is_ok());
+    assert!(test
+        .unwrap()
+        .0
+        .to_rfc3339()
+        .starts_with("2023-01-05T00:00:00"));
+}
+
+#[test]
+fn test_go_time_string() {
+    let date = "2009-11-10 23:00:00 +0000 UTC m=+0.000000001";
+    let test = date.parse::<DateTimeFixedOffset>();
+    assert!(test.is_ok());
+    assert_eq!(test.unwrap().0.to_rfc3339(), "2009-11-10T23:00:00+00:00");
+}
+
+#[test]
+fn test_go_time_string_with_nanoseconds() {
+    let date = "2023-01-05 07:27:19.123456789 -0700 MST";
+    let test = date.parse::<DateTimeFixedOffset>();
+    assert!(test.is_ok());
+    assert_eq!(
+        test.unwrap().0.to_rfc3339(),
+        "2023-01-05T07:27:19.123456789-07:00"
+    );
+}
+
+#[test]
+fn test_elasticsearch_space_separated_z() {
+    let date = "2023-01-05 07:27:19.123Z";
+    let test = date.parse::<DateTimeFixedOffset>();
+    assert!(test.is_ok());
+    assert_eq!(
+        test.unwrap().0.to_rfc3339(),
+        "2023-01-05T07:27:19.123+00:00"
+    );
+}
+
+#[test]
+fn test_four_digits_as_epoch() {
+    let date = "2023";
+    let test = date.parse::<DateTimeFixedOffset>();
+    assert!(test.is_ok());
+    assert_eq!(test.unwrap().0.to_rfc3339(), "1970-01-01T00:33:43+00:00");
+}
+
+#[test]
+fn test_four_digits_as_year() {
+    use crate::Parser;
+    let date = "2023";
+    let test = Parser::new().reject_year_only(false).parse(date);
+    assert!(test.is_ok());
+    assert!(test
+        .unwrap()
+        .0
+        .to_rfc3339()
+        .starts_with("2023-01-01T00:00:00"));
+}
+
+#[test]
+fn test_javascript_date_to_string() {
+    let date = "Wed Jul 01 1970 13:13:55 GMT+0000 (Coordinated Universal Time)";
+    let test = date.parse::<DateTimeFixedOffset>();
+    assert!(test.is_ok());
+    assert_eq!(test.unwrap().0.to_rfc3339(), "1970-07-01T13:13:55+00:00");
+}
+
+#[test]
+fn test_python_isoformat_space() {
+    let date = "2023-01-05 07:27:19.123456";
+    let test = date.parse::<DateTimeFixedOffset>();
+    assert!(test.is_ok());
+    assert!(test
+        .unwrap()
+        .0
+        .to_rfc3339()
+        .starts_with("2023-01-05T07:27:19.123456"));
+}
+
+#[test]
+fn test_python_isoformat_t() {
+    let date = "2023-01-05T07:27:19.123456";
+    let test = date.parse::<DateTimeFixedOffset>();
+    assert!(test.is_ok());
+    assert!(test
+        .unwrap()
+        .0
+        .to_rfc3339()
+        .starts_with("2023-01-05T07:27:19.123456"));
+}
+
+#[test]
+fn test_python_isoformat_utc() {
+    let date = "2023-01-05T07:27:19.123456+00:00";
+    let test = date.parse::<DateTimeFixedOffset>();
+    assert!(test.is_ok());
+    assert_eq!(
+        test.unwrap().0.to_rfc3339(),
+        "2023-01-05T07:27:19.123456+00:00"
+    );
+}
+
+#[test]
+fn test_day_offset_with_time() {
+    use crate::Parser;
+    let now = chrono::DateTime::parse_from_rfc3339("2023-01-05T07:27:19+02:00").unwrap();
+    let test = Parser::new().now(now).parse("+1d 09:00");
+    assert!(test.is_ok());
+    assert_eq!(test.unwrap().0.to_rfc3339(), "2023-01-06T09:00:00+02:00");
+    let test = Parser::new().now(now).parse("-5d 18:30:00");
+    assert!(test.is_ok());
+    assert_eq!(test.unwrap().0.to_rfc3339(), "2022-12-31T18:30:00+02:00");
+}
+
+#[test]
+fn test_syslog_abbreviated_month_tz_before_year() {
+    let date = "Jan 05 07:27:19 CET 2023";
+    let test = date.parse::<DateTimeFixedOffset>();
+    assert!(test.is_ok());
+    assert_eq!(test.unwrap().0.to_rfc3339(), "2023-01-05T07:27:19+01:00");
+}
+
+#[test]
+fn test_http_imf_fixdate() {
+    let date = "Sun, 06 Nov 1994 08:49:37 GMT";
+    let test = date.parse::<DateTimeFixedOffset>();
+    assert!(test.is_ok());
+    assert_eq!(test.unwrap().0.to_rfc3339(), "1994-11-06T08:49:37+00:00");
+}
+
+#[test]
+fn test_http_rfc850() {
+    let date = "Sunday, 06-Nov-94 08:49:37 GMT";
+    let test = date.parse::<DateTimeFixedOffset>();
+    assert!(test.is_ok());
+    assert_eq!(test.unwrap().0.to_rfc3339(), "1994-11-06T08:49:37+00:00");
+}
+
+#[test]
+fn test_http_asctime() {
+    let date = "Sun Nov  6 08:49:37 1994";
+    let test = date.parse::<DateTimeFixedOffset>();
+    assert!(test.is_ok());
+    assert!(test
+        .unwrap()
+        .0
+        .to_rfc3339()
+        .starts_with("1994-11-06T08:49:37"));
+}
+
+#[test]
+fn test_tab_separated() {
+    let date = "2023-01-05\t07:27:19\t+0200";
+    let test = date.parse::<DateTimeFixedOffset>();
+    assert!(test.is_ok());
+    assert_eq!(test.unwrap().0.to_rfc3339(), "2023-01-05T07:27:19+02:00");
+}
+
+#[test]
+fn test_nbsp_separated() {
+    let date = "5\u{a0}Jan\u{a0}2023\u{a0}\u{a0}07:27:19\u{a0}PST ";
+    let test = date.parse::<DateTimeFixedOffset>();
+    assert!(test.is_ok());
+    assert_eq!(test.unwrap().0.to_rfc3339(), "2023-01-05T07:27:19-08:00");
+}
+
+#[test]
+fn test_min_confidence() {
+    use crate::{ParseError, ParsedVia, Parser};
+    let date = "Feb 12";
+    assert!(date.parse::<DateTimeFixedOffset>().is_ok());
+    let test = Parser::new().min_confidence(0.9).parse(date);
+    assert!(matches!(
+        test,
+        Err(ParseError::LowConfidence {
+            via: ParsedVia::Others,
+            ..
+        })
+    ));
+    let test = Parser::new()
+        .min_confidence(0.9)
+        .parse("2023-01-05T07:27:19Z");
+    assert!(test.is_ok());
+}
+
+#[test]
+fn test_dotted_short_year_dmy() {
+    use crate::{DateOrder, Parser};
+    let date = "05.01.23";
+    let test = Parser::new().date_order(DateOrder::Dmy).parse(date);
+    assert!(test.is_ok());
+    assert!(test
+        .unwrap()
+        .0
+        .to_rfc3339()
+        .starts_with("2023-01-05T00:00:00"));
+}
+
+#[test]
+fn test_dotted_short_year_mdy() {
+    let date = "05.01.23";
+    let test = date.parse::<DateTimeFixedOffset>();
+    assert!(test.is_ok());
+    assert!(test
+        .unwrap()
+        .0
+        .to_rfc3339()
+        .starts_with("2023-05-01T00:00:00"));
+}
+
+#[test]
+fn test_parse_with_source() {
+    use crate::{parse_with_source, ParsedVia};
+    let cases = [
+        ("1672903639", ParsedVia::UnixTimestamp),
+        ("2023-01-05T07:27:19+02:00", ParsedVia::Rfc3339),
+        ("2023-01-05 07:27:19", ParsedVia::DatetimeWithoutTz),
+        ("2023-01-05", ParsedVia::DateWithoutTz),
+        (
+            "Jan 05 07:27:19 CET 2023",
+            ParsedVia::DatetimeWithTzBeforeYear,
+        ),
+        ("Feb 12", ParsedVia::Others),
+    ];
+    for (date, expected) in cases {
+        let test = parse_with_source(date);
+        assert!(test.is_ok());
+        assert_eq!(test.unwrap().1, expected, "{}", date);
+    }
+}
+
+#[test]
+fn test_normalize() {
+    use crate::normalize;
+    assert_eq!(normalize("12/13/2000,  12:12:12"), "12-13-2000 12:12:12");
+    assert_eq!(normalize("1970.12.31"), "1970-12-31");
+}
+
+#[test]
+fn test_bom_prefixed_rfc3339() {
+    let date = "\u{feff}2023-01-05T07:27:19+02:00";
+    let test = date.parse::<DateTimeFixedOffset>();
+    assert!(test.is_ok());
+    assert_eq!(test.unwrap().0.to_rfc3339(), "2023-01-05T07:27:19+02:00");
+}
+
+#[test]
+fn test_zero_width_wrapped() {
+    let date = "\u{200b}2023-01-05 07:27:19 PST\u{200d}";
+    let test = date.parse::<DateTimeFixedOffset>();
+    assert!(test.is_ok());
+    assert_eq!(test.unwrap().0.to_rfc3339(), "2023-01-05T07:27:19-08:00");
+}
+
+#[test]
+fn test_parser_as_fn() {
+    use crate::{DateOrder, Parser};
+    let parse = Parser::new().date_order(DateOrder::Dmy).as_fn();
+    let dates = ["05.01.23", "5 Jan 2023 12:00:00 GMT", "1672903639"]
+        .iter()
+        .map(|x| parse(x))
+        .collect::<Vec<_>>();
+    assert!(dates.iter().all(|x| x.is_ok()));
+    let dates = dates
+        .into_iter()
+        .map(|x| x.unwrap().0.date_naive().to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(dates, ["2023-01-05", "2023-01-05", "2023-01-05"]);
+    assert!(parse("not a date").is_err());
+}
+
+#[test]
+fn test_scientific_notation_not_a_timestamp() {
+    assert!("2e3".parse::<DateTimeFixedOffset>().is_err());
+    assert!("inf".parse::<DateTimeFixedOffset>().is_err());
+    let date = "1672903639.5";
+    let test = date.parse::<DateTimeFixedOffset>();
+    assert!(test.is_ok());
+    assert_eq!(test.unwrap().0.to_rfc3339(), "2023-01-05T07:27:19+00:00");
+}
+
+#[test]
+fn test_out_of_range_components() {
+    use crate::ParseError;
+    let test = "2023-02-30".parse::<DateTimeFixedOffset>();
+    assert!(matches!(
+        test,
+        Err(ParseError::OutOfRange {
+            component: "day",
+            ..
+        })
+    ));
+    let test = "2023-13-01".parse::<DateTimeFixedOffset>();
+    assert!(matches!(
+        test,
+        Err(ParseError::OutOfRange {
+            component: "month",
+            ..
+        })
+    ));
+    assert_eq!(
+        "2023-01-05 25:00:00".parse::<DateTimeFixedOffset>().err(),
+        Some(ParseError::OutOfRange {
+            component: "hour",
+            value: "25".to_string()
+        })
+    );
+    // a valid day-first reading still wins over the month-first one
+    assert!("13-02-2023".parse::<DateTimeFixedOffset>().is_ok());
+}
+
+#[test]
+fn test_short_offset_iso_datetime() {
+    let date = "2023-01-05T07:27:19+05";
+    let test = date.parse::<DateTimeFixedOffset>();
+    assert!(test.is_ok());
+    assert_eq!(test.unwrap().0.to_rfc3339(), "2023-01-05T07:27:19+05:00");
+    let date = "2023-01-05T07:27:19.123-03";
+    let test = date.parse::<DateTimeFixedOffset>();
+    assert!(test.is_ok());
+    assert_eq!(
+        test.unwrap().0.to_rfc3339(),
+        "2023-01-05T07:27:19.123-03:00"
+    );
+}
+
+#[test]
+fn test_google_calendar_at_connector() {
+    let date = "5 January 2023 at 14:30";
+    let test = date.parse::<DateTimeFixedOffset>();
+    assert!(test.is_ok());
+    assert!(test
+        .unwrap()
+        .0
+        .to_rfc3339()
+        .starts_with("2023-01-05T14:30:00"));
+    let date = "January 5, 2023 at 2:30 PM";
+    let test = date.parse::<DateTimeFixedOffset>();
+    assert!(test.is_ok());
+    assert!(test
+        .unwrap()
+        .0
+        .to_rfc3339()
+        .starts_with("2023-01-05T14:30:00"));
+}
+
+#[test]
+fn test_noon_and_midnight() {
+    use crate::Parser;
+    let now = chrono::DateTime::parse_from_rfc3339("2023-01-05T23:30:00+00:00").unwrap();
+    let offset = chrono::FixedOffset::east_opt(3600).unwrap();
+    let parser = Parser::new().now(now).local_offset(offset);
+    // the current date is the one in the local offset
+    for (date, expected) in [
+        ("noon", "2023-01-06T12:00:00+01:00"),
+        ("NOON", "2023-01-06T12:00:00+01:00"),
+        ("Jan 5 2023 midnight", "2023-01-05T00:00:00+01:00"),
+    ] {
+        let test = parser.parse(date);
+        assert!(test.is_ok(), "{}", date);
+        assert_eq!(test.unwrap().0.to_rfc3339(), expected, "{}", date);
+    }
+}
+
+#[test]
+fn test_leap_second() {
+    use chrono::Timelike;
+    let date = "2016-12-31T23:59:60Z";
+    let test = date.parse::<DateTimeFixedOffset>();
+    assert!(test.is_ok());
+    let test = test.unwrap().0;
+    assert_eq!(test.to_rfc3339(), "2016-12-31T23:59:60+00:00");
+    assert_eq!(test.timestamp(), 1483228799);
+    assert!(test.nanosecond() >= 1_000_000_000);
+    let date = "2016-12-31 23:59:60.5";
+    let test = date.parse::<DateTimeFixedOffset>();
+    assert!(test.is_ok());
+    assert!(test
+        .unwrap()
+        .0
+        .to_rfc3339()
+        .starts_with("2016-12-31T23:59:60.500"));
+}
+
+#[test]
+fn test_pre_epoch() {
+    let dates = [
+        "1900-01-01T00:00:00Z",
+        "1900-01-01T00:00:00+00:00",
+        "Mon, 1 Jan 1900 00:00:00 GMT",
+        "-2208988800",
+        "-2208988800000",
+        "-2208988800000000",
+        "-2208988800000000000",
+    ];
+    for date in dates {
+        let test = date.parse::<DateTimeFixedOffset>();
+        assert!(test.is_ok());
+        assert_eq!(test.unwrap().0.to_rfc3339(), "1900-01-01T00:00:00+00:00");
+    }
+    let date = "1900-01-01T00:00:00-05:00";
+    let test = date.parse::<DateTimeFixedOffset>();
+    assert!(test.is_ok());
+    assert_eq!(test.unwrap().0.timestamp(), -2208970800);
+    for date in ["1900-01-01", "1900-01-01 00:00:00", "Jan 1 1900"] {
+        let test = date.parse::<DateTimeFixedOffset>();
+        assert!(test.is_ok());
+        assert!(test
+            .unwrap()
+            .0
+            .to_rfc3339()
+            .starts_with("1900-01-01T00:00:00"));
+    }
+    let date = "-1500";
+    let test = date.parse::<DateTimeFixedOffset>();
+    assert!(test.is_ok());
+    assert_eq!(test.unwrap().0.to_rfc3339(), "1969-12-31T23:35:00+00:00");
+    let date = "-1.5";
+    let test = date.parse::<DateTimeFixedOffset>();
+    assert!(test.is_ok());
+    assert_eq!(test.unwrap().0.timestamp(), -1);
+}
+
+#[test]
+fn test_lowercase_iso_separators() {
+    let date = "2023-01-05t07:27:19z";
+    let test = date.parse::<DateTimeFixedOffset>();
+    assert!(test.is_ok());
+    assert_eq!(test.unwrap().0.to_rfc3339(), "2023-01-05T07:27:19+00:00");
+    let date = "2023-01-05t07:27:19.5+02:00";
+    let test = date.parse::<DateTimeFixedOffset>();
+    assert!(test.is_ok());
+    assert_eq!(
+        test.unwrap().0.to_rfc3339(),
+        "2023-01-05T07:27:19.500+02:00"
+    );
+    assert_eq!(
+        crate::normalize("2023-01-05t07:27:19 est"),
+        "2023-01-05T07:27:19 est"
+    );
+}
+
+#[test]
+fn test_output_timezone() {
+    use crate::Parser;
+    use chrono::FixedOffset;
+    let parser = Parser::new().output_timezone(FixedOffset::east_opt(0).unwrap());
+    let dates = [
+        "2023-01-05T09:27:19+02:00",
+        "Thu, 05 Jan 2023 02:27:19 EST",
+        "2023-01-05 07:27:19 GMT",
+        "1672903639",
+    ];
+    for date in dates {
+        let test = parser.parse(date);
+        assert!(test.is_ok());
+        assert_eq!(test.unwrap().0.to_rfc3339(), "2023-01-05T07:27:19+00:00");
+    }
+    let parser = Parser::new().output_timezone(FixedOffset::east_opt(9 * 3600).unwrap());
+    let test = parser.parse_with_source("2023-01-05T07:27:19Z");
+    assert!(test.is_ok());
+    assert_eq!(test.unwrap().0 .0.to_rfc3339(), "2023-01-05T16:27:19+09:00");
+}
+
+#[test]
+fn test_basic_iso() {
+    let date = "20230105T072719Z";
+    let test = date.parse::<DateTimeFixedOffset>();
+    assert!(test.is_ok());
+    assert_eq!(test.unwrap().0.to_rfc3339(), "2023-01-05T07:27:19+00:00");
+    let date = "20230105T072719+0200";
+    let test = date.parse::<DateTimeFixedOffset>();
+    assert!(test.is_ok());
+    assert_eq!(test.unwrap().0.to_rfc3339(), "2023-01-05T07:27:19+02:00");
+    let date = "20230105T072719";
+    let test = date.parse::<DateTimeFixedOffset>();
+    assert!(test.is_ok());
+    assert!(test
+        .unwrap()
+        .0
+        .to_rfc3339()
+        .starts_with("2023-01-05T07:27:19"));
+}
+
+#[test]
+fn test_basic_iso_date_before_timestamp() {
+    use crate::{parse_with_source, ParsedVia};
+    let date = "20230105";
+    let test = parse_with_source(date);
+    assert!(test.is_ok());
+    let (dt, via) = test.unwrap();
+    assert_eq!(via, ParsedVia::BasicIso);
+    assert!(dt.to_rfc3339().starts_with("2023-01-05T00:00:00"));
+    // not a calendar date, so still a timestamp
+    let test = parse_with_source("16729036");
+    assert!(test.is_ok());
+    assert_eq!(test.unwrap().1, ParsedVia::UnixTimestamp);
+}
+
+#[test]
+fn test_month_year() {
+    for date in ["January 2023", "Jan 2023", "01 2023", "1 2023"] {
+        let test = date.parse::<DateTimeFixedOffset>();
+        assert!(test.is_ok());
+        assert!(test
+            .unwrap()
+            .0
+            .to_rfc3339()
+            .starts_with("2023-01-01T00:00:00"));
+    }
+    assert!("13 2023".parse::<DateTimeFixedOffset>().is_err());
+}
+
+#[test]
+fn test_parser_pipeline() {
+    use crate::{ParsedVia, Parser};
+    let default = Parser::new().pipeline();
+    assert_eq!(default.first(), Some(&ParsedVia::BasicIso));
+    assert_eq!(default.last(), Some(&ParsedVia::Others));
+    assert!(!default.contains(&ParsedVia::YearOnly));
+    let years = Parser::new().reject_year_only(false).pipeline();
+    assert_ne!(default, years);
+    assert_eq!(years.first(), Some(&ParsedVia::YearOnly));
+    assert_eq!(years.len(), default.len() + 1);
+}
+
+#[test]
+fn test_numeric_offset_retained() {
+    let dates = [
+        ("Feb 14 2022 13:13:55 GMT+0530", "2022-02-14T13:13:55+05:30"),
+        (
+            "Feb 14 2022 13:13:55 GMT+05:30",
+            "2022-02-14T13:13:55+05:30",
+        ),
+        ("Feb 14 2022 13:13:55 PST+0530", "2022-02-14T13:13:55+05:30"),
+        ("Feb 14 2022 13:13:55 GMT-3", "2022-02-14T13:13:55-03:00"),
+        (
+            "2023-01-05 07:27:19 IST +05:30",
+            "2023-01-05T07:27:19+05:30",
+        ),
+        ("2023-01-05 07:27:19 +05:30", "2023-01-05T07:27:19+05:30"),
+        (
+            "Mon, 6 Jul 1970 15:30:00 +0545",
+            "1970-07-06T15:30:00+05:45",
+        ),
+        ("1 Jan 1970 22:00:00 -0330", "1970-01-01T22:00:00-03:30"),
+    ];
+    for (date, expected) in dates {
+        let test = date.parse::<DateTimeFixedOffset>();
+        assert!(test.is_ok());
+        assert_eq!(test.unwrap().0.to_rfc3339(), expected);
+    }
+}
+
+#[test]
+fn test_time_with_dotted_meridiem_and_tz() {
+    let date = "7:27:19 a.m. PDT";
+    let test = date.parse::<DateTimeFixedOffset>();
+    assert!(test.is_ok());
+    let test = test.unwrap().0;
+    assert!(test.to_rfc3339().ends_with("T07:27:19-07:00"));
+    let date = "7:27:19 P.M. PDT";
+    let test = date.parse::<DateTimeFixedOffset>();
+    assert!(test.is_ok());
+    assert!(test.unwrap().0.to_rfc3339().ends_with("T19:27:19-07:00"));
+    let date = "7:27 p.m.";
+    let test = date.parse::<DateTimeFixedOffset>();
+    assert!(test.is_ok());
+    assert!(test.unwrap().0.to_rfc3339().contains("T19:27:00"));
+}
+
+#[test]
+fn test_dash_separated_filename_timestamp() {
+    use crate::{parse_with_source, ParsedVia};
+    let date = "20230105-072719";
+    let test = parse_with_source(date);
+    assert!(test.is_ok());
+    let (dt, via) = test.unwrap();
+    assert_eq!(via, ParsedVia::BasicIso);
+    assert!(dt.to_rfc3339().starts_with("2023-01-05T07:27:19"));
+}
+
+#[test]
+fn test_display_and_ordering() {
+    let date = "Thu, 05 Jan 2023 07:27:19 +0200";
+    let test = date.parse::<DateTimeFixedOffset>();
+    assert!(test.is_ok());
+    assert_eq!(test.unwrap().to_string(), "2023-01-05T07:27:19+02:00");
+    let utc = "2023-01-05T05:27:19Z"
+        .parse::<DateTimeFixedOffset>()
+        .unwrap();
+    let ist = "2023-01-05T10:57:19+05:30"
+        .parse::<DateTimeFixedOffset>()
+        .unwrap();
+    assert_eq!(utc, ist);
+    let mut dates = [
+        "2023-01-06T00:00:00Z",
+        "1672903639",
+        "2023-01-05T09:27:19+05:30",
+    ]
+    .iter()
+    .map(|x| x.parse::<DateTimeFixedOffset>().unwrap())
+    .collect::<Vec<_>>();
+    dates.sort();
+    assert_eq!(
+        dates.iter().map(|x| x.to_string()).collect::<Vec<_>>(),
+        [
+            "2023-01-05T09:27:19+05:30",
+            "2023-01-05T07:27:19+00:00",
+            "2023-01-06T00:00:00+00:00"
+        ]
+    );
+}
+
+#[test]
+fn test_parse_many() {
+    use crate::{parse_many, parse_many_ok, ParseError};
+    let items = vec![
+        "2023-01-05T07:27:19Z".to_string(),
+        "".to_string(),
+        "not a date".to_string(),
+        "1672903639".to_string(),
+    ];
+    let results = parse_many(&items);
+    assert_eq!(results.len(), 4);
+    assert!(results[0].is_ok());
+    assert_eq!(results[1], Err(ParseError::Empty));
+    assert!(matches!(results[2], Err(ParseError::Unrecognized(_))));
+    assert!(results[3].is_ok());
+    let ok = parse_many_ok(&items);
+    assert_eq!(ok.iter().map(|(i, _)| *i).collect::<Vec<_>>(), [0, 3]);
+    assert_eq!(ok[0].1, ok[1].1);
+}
+
+#[test]
+fn test_coerce_invalid_to_nearest_valid() {
+    use crate::{ParseError, Parser};
+    let date = "2023-02-30 25:61:00";
+    let strict = Parser::new().parse(date);
+    assert!(matches!(strict, Err(ParseError::OutOfRange { .. })));
+    let lenient = Parser::new()
+        .coerce_invalid_to_nearest_valid(true)
+        .parse(date);
+    assert!(lenient.is_ok());
+    assert!(lenient
+        .unwrap()
+        .0
+        .to_rfc3339()
+        .starts_with("2023-02-28T23:59:00"));
+    let lenient = Parser::new()
+        .coerce_invalid_to_nearest_valid(true)
+        .parse("2023-13-45T24:00:00Z");
+    assert!(lenient.is_ok());
+    assert_eq!(lenient.unwrap().0.to_rfc3339(), "2023-12-31T23:00:00+00:00");
+}
+
+#[test]
+fn test_parse_lines() {
+    use crate::{parse_lines, ParseError, Parser};
+    use std::io::Cursor;
+    let text = "2023-01-05T07:27:19Z\n\nnot a date\r\n1672903639\n";
+    let results = parse_lines(Cursor::new(text)).collect::<Vec<_>>();
+    assert_eq!(
+        results.iter().map(|(line, _)| *line).collect::<Vec<_>>(),
+        [1, 3, 4]
+    );
+    assert!(results[0].1.is_ok());
+    assert!(matches!(results[1].1, Err(ParseError::Unrecognized(_))));
+    assert_eq!(results[0].1, results[2].1);
+    let results = Parser::new()
+        .skip_blank_lines(false)
+        .parse_lines(Cursor::new(text))
+        .collect::<Vec<_>>();
+    assert_eq!(results.len(), 4);
+    assert_eq!(results[1], (2, Err(ParseError::Empty)));
+}
+
+#[test]
+fn test_dotnet() {
+    use crate::{parse_with_source, ParsedVia};
+    let date = "/Date(1672903639123)/";
+    let test = date.parse::<DateTimeFixedOffset>();
+    assert!(test.is_ok());
+    assert_eq!(
+        test.unwrap().0.to_rfc3339(),
+        "2023-01-05T07:27:19.123+00:00"
+    );
+    let date = "/Date(1672903639123+0200)/";
+    let test = date.parse::<DateTimeFixedOffset>();
+    assert!(test.is_ok());
+    assert_eq!(
+        test.unwrap().0.to_rfc3339(),
+        "2023-01-05T09:27:19.123+02:00"
+    );
+    let date = "638085004391230000";
+    let test = parse_with_source(date);
+    assert!(test.is_ok());
+    let (dt, via) = test.unwrap();
+    assert_eq!(via, ParsedVia::DotNet);
+    assert_eq!(dt.to_rfc3339(), "2023-01-05T07:27:19.123+00:00");
+    // nanoseconds since the epoch are still a timestamp
+    let test = parse_with_source("1672903639123000000");
+    assert!(test.is_ok());
+    assert_eq!(test.unwrap().1, ParsedVia::UnixTimestamp);
+}
+
+#[test]
+fn test_excel_serial() {
+    use crate::{ExcelEpoch, ParsedVia, Parser};
+    let parser = Parser::new().excel_epoch(ExcelEpoch::Epoch1900);
+    let test = parser.parse_with_source("44927.3107");
+    assert!(test.is_ok());
+    let (dt, via) = test.unwrap();
+    assert_eq!(via, ParsedVia::ExcelSerial);
+    assert!(dt.0.to_rfc3339().starts_with("2023-01-01T07:27:24.480"));
+    let dates = [
+        ("1", "1900-01-01"),
+        ("59", "1900-02-28"),
+        ("61", "1900-03-01"),
+    ];
+    for (serial, expected) in dates {
+        let test = parser.parse(serial);
+        assert!(test.is_ok());
+        assert_eq!(test.unwrap().0.date_naive().to_string(), expected);
+    }
+    // 1900-02-29 does not exist, so serial 60 falls through to the timestamp branch
+    let test = parser.parse_with_source("60");
+    assert!(test.is_ok());
+    assert_eq!(test.unwrap().1, ParsedVia::UnixTimestamp);
+    let parser = Parser::new().excel_epoch(ExcelEpoch::Epoch1904);
+    let test = parser.parse("43465.5");
+    assert!(test.is_ok());
+    assert!(test
+        .unwrap()
+        .0
+        .to_rfc3339()
+        .starts_with("2023-01-01T12:00:00"));
+    // opt-in only: by default a bare number is a timestamp
+    let test = Parser::new().parse_with_source("44927");
+    assert!(test.is_ok());
+    assert_eq!(test.unwrap().1, ParsedVia::UnixTimestamp);
+}
+
+#[test]
+fn test_timestamp_out_of_range() {
+    use crate::ParseError;
+    let dates = ["inf", "1e999", "NaN", "-inf", "100000000000000000000000"];
+    for date in dates {
+        let test = date.parse::<DateTimeFixedOffset>();
+        assert!(matches!(
+            test,
+            Err(ParseError::OutOfRange {
+                component: "timestamp",
+                ..
+            })
+        ));
+    }
+}
+
+#[test]
+fn test_injected_now_and_local_offset() {
+    use crate::Parser;
+    use chrono::{DateTime, FixedOffset};
+    let offset = FixedOffset::west_opt(3 * 3600).unwrap();
+    let now = DateTime::parse_from_rfc3339("2023-01-05T22:00:00-03:00").unwrap();
+    let parser = Parser::new().now(now).local_offset(offset);
+    let dates = [
+        ("09:00:00", "2023-01-05T09:00:00-03:00"),
+        ("3:00pm", "2023-01-05T15:00:00-03:00"),
+        ("Feb 12", "2023-02-12T00:00:00-03:00"),
+        ("2023-01-05 07:27:19", "2023-01-05T07:27:19-03:00"),
+        ("+1d 09:00", "2023-01-06T09:00:00-03:00"),
+        ("2023-01-05T07:27:19+02:00", "2023-01-05T07:27:19+02:00"),
+    ];
+    for (date, expected) in dates {
+        let test = parser.parse(date);
+        assert!(test.is_ok());
+        assert_eq!(test.unwrap().0.to_rfc3339(), expected);
+    }
+}
+
+#[test]
+fn test_offset_with_seconds() {
+    let date = "1880-01-01 12:00:00 +00:06:42";
+    let test = date.parse::<DateTimeFixedOffset>();
+    assert!(test.is_ok());
+    let test = test.unwrap().0;
+    assert_eq!(test.offset().local_minus_utc(), 402);
+    assert_eq!(test.to_string(), "1880-01-01 12:00:00 +00:06:42");
+    let again = test.to_string().parse::<DateTimeFixedOffset>();
+    assert!(again.is_ok());
+    assert_eq!(again.unwrap().0.offset(), test.offset());
+    // Display keeps the seconds of the offset, while RFC 3339 rounds them
+    let test = DateTimeFixedOffset(test);
+    assert_eq!(test.to_string(), "1880-01-01T12:00:00+00:06:42");
+    assert_eq!(test.0.to_rfc3339(), "1880-01-01T12:00:00+00:07");
+    let again = test.to_string().parse::<DateTimeFixedOffset>();
+    assert!(again.is_ok());
+    let again = again.unwrap();
+    assert_eq!(again, test);
+    assert_eq!(again.0.offset(), test.0.offset());
+    let date = "1880-01-01T12:00:00.5-05:30:15";
+    let test = date.parse::<DateTimeFixedOffset>();
+    assert!(test.is_ok());
+    let test = test.unwrap();
+    assert_eq!(test.0.offset().local_minus_utc(), -19815);
+    assert_eq!(test.to_string(), "1880-01-01T12:00:00.500-05:30:15");
+    assert_eq!(test.to_string().parse::<DateTimeFixedOffset>(), Ok(test));
+    let date = "1880-01-01T12:00:00+05:30";
+    let test = date.parse::<DateTimeFixedOffset>();
+    assert!(test.is_ok());
+    assert_eq!(test.unwrap().0.to_rfc3339(), "1880-01-01T12:00:00+05:30");
+}
+
+#[test]
+fn test_standardize_date_borrows() {
+    use crate::standardize_date;
+    use alloc::borrow::Cow;
+    for date in [
+        "2023-01-05T07:27:19+02:00",
+        "1672903639",
+        "Jan 5 2023 7:27:19 pm PDT",
+    ] {
+        assert!(matches!(standardize_date(date), Cow::Borrowed(x) if x == date));
+    }
+    for (date, expected) in [
+        ("12/13/2000,  12:12:12", "12-13-2000 12:12:12"),
+        ("\u{feff}2023-01-05 07:27:19 UTC", "2023-01-05T07:27:19 GMT"),
+        ("5 January 2023 at noon", "5 January 2023 12:00:00"),
+        (
+            "Wed, 1 Jul 1970 7:27:19 p.m. UT",
+            "Wed 1 Jul 1970 7:27:19 pm GMT",
+        ),
+        ("2023-01-05t07:27:19z", "2023-01-05T07:27:19Z"),
+    ] {
+        assert!(matches!(standardize_date(date), Cow::Owned(x) if x == expected));
+    }
+}
+
+#[test]
+fn test_mmmddyyyy_hms_tz() {
+    use crate::{standardize_date, try_mmmddyyyy_hms_tz};
+    for (date, expected) in [
+        (
+            "Feb 14 2022 13:13:55 GMT+00:00",
+            "2022-02-14T13:13:55+00:00",
+        ),
+        ("Feb 14 2022 13:13:55 GMT+0000", "2022-02-14T13:13:55+00:00"),
+        (
+            "Wed Jul 1 1970 13:13:55 GMT+0000",
+            "1970-07-01T13:13:55+00:00",
+        ),
+        ("Feb 14 2022 13:13:55 PST-08", "2022-02-14T13:13:55-08:00"),
+        (
+            "2023-01-05 07:27:19 IST +05:30",
+            "2023-01-05T07:27:19+05:30",
+        ),
+    ] {
+        let test = try_mmmddyyyy_hms_tz(&standardize_date(date));
+        assert!(test.is_ok(), "{}", date);
+        assert_eq!(test.unwrap().to_rfc3339(), expected);
+    }
+    for date in ["GMT+0000", "+05:30", "Feb 14 2022 13:13:55", ""] {
+        assert!(try_mmmddyyyy_hms_tz(date).is_err(), "{}", date);
+    }
+}
+
+#[test]
+fn test_multi_word_timezone() {
+    use crate::Parser;
+    for (date, expected) in [
+        (
+            "1970-12-25 16:16:16 Pacific Standard Time",
+            "1970-12-25T16:16:16-08:00",
+        ),
+        (
+            "1 Jan 1970 22:00:00 Central European Summer Time",
+            "1970-01-01T22:00:00+02:00",
+        ),
+        (
+            "1 Jan 1970 22:00:00 australian eastern standard time",
+            "1970-01-01T22:00:00+10:00",
+        ),
+        ("1 Jan 1970 22:00:00 GMT-3", "1970-01-01T22:00:00-03:00"),
+    ] {
+        let test = date.parse::<DateTimeFixedOffset>();
+        assert!(test.is_ok(), "{}", date);
+        assert_eq!(test.unwrap().0.to_rfc3339(), expected);
+    }
+    let now = chrono::DateTime::parse_from_rfc3339("2023-01-05T00:00:00+00:00").unwrap();
+    let parser = Parser::new().now(now);
+    for (date, expected) in [
+        (
+            "10:00 pm Eastern Daylight Time",
+            "2023-01-05T22:00:00-04:00",
+        ),
+        ("16:16:16 GMT+0200", "2023-01-05T16:16:16+02:00"),
+    ] {
+        let test = parser.parse(date);
+        assert!(test.is_ok(), "{}", date);
+        assert_eq!(test.unwrap().0.to_rfc3339(), expected);
+    }
+    assert!("1970-12-25 16:16:16 Foo Bar"
+        .parse::<DateTimeFixedOffset>()
+        .is_err());
+}
+
+#[test]
+fn test_time_without_tz_in_utc() {
+    use crate::Parser;
+    use chrono::{DateTime, FixedOffset};
+    let utc = FixedOffset::east_opt(0).unwrap();
+    let test = Parser::new().local_offset(utc).parse("15:30:00");
+    assert!(test.is_ok());
+    assert_eq!(test.unwrap().0.offset(), &utc);
+    // 01:00 in +02:00 is still the day before in UTC
+    let now = DateTime::parse_from_rfc3339("2023-01-05T01:00:00+02:00").unwrap();
+    let parser = Parser::new().now(now).local_offset(utc);
+    let test = parser.parse("15:30:00");
+    assert!(test.is_ok());
+    assert_eq!(test.unwrap().0.to_rfc3339(), "2023-01-04T15:30:00+00:00");
+    let offset = FixedOffset::east_opt(2 * 3600).unwrap();
+    let test = parser.local_offset(offset).parse("15:30:00");
+    assert!(test.is_ok());
+    assert_eq!(test.unwrap().0.to_rfc3339(), "2023-01-05T15:30:00+02:00");
+}
+
+#[test]
+fn test_chrono_conversions() {
+    use chrono::{DateTime, FixedOffset, Utc};
+    let date = "2023-01-05T07:27:19.123+02:00";
+    let parsed = date.parse::<DateTimeFixedOffset>().unwrap();
+    let fixed: DateTime<FixedOffset> = parsed.into();
+    assert_eq!(fixed.to_rfc3339(), "2023-01-05T07:27:19.123+02:00");
+    let utc: DateTime<Utc> = parsed.into();
+    assert_eq!(utc.to_rfc3339(), "2023-01-05T05:27:19.123+00:00");
+    assert_eq!(DateTimeFixedOffset::from(fixed), parsed);
+    assert_eq!(DateTimeFixedOffset::from(fixed).0.offset(), fixed.offset());
+}
+
+#[cfg(feature = "time")]
+#[test]
+fn test_time_conversions() {
+    use crate::{parse_to_time, ParseError};
+    let date = "2023-01-05T07:27:19.123456789-05:30";
+    let test = parse_to_time(date);
+    assert!(test.is_ok());
+    let test = test.unwrap();
+    assert_eq!(test.offset().whole_seconds(), -(5 * 3600 + 30 * 60));
+    assert_eq!(test.nanosecond(), 123_456_789);
+    let parsed = date.parse::<DateTimeFixedOffset>().unwrap();
+    assert_eq!(test.unix_timestamp(), parsed.0.timestamp());
+    assert_eq!(
+        (test.year(), u8::from(test.month()), test.day()),
+        (2023, 1, 5)
+    );
+    assert_eq!((test.hour(), test.minute(), test.second()), (7, 27, 19));
+    // a leap second has no equivalent in `time`
+    let leap = "2016-12-31T23:59:60Z"
+        .parse::<DateTimeFixedOffset>()
+        .unwrap();
+    assert!(matches!(
+        time::OffsetDateTime::try_from(leap),
+        Err(ParseError::OutOfRange {
+            component: "second",
+            ..
+        })
+    ));
+}
+
+#[test]
+fn test_parse_verbose() {
+    use crate::parse_verbose;
+    let test = parse_verbose("2023-01-05T07:27:19+02:00");
+    assert!(test.is_ok());
+    let test = parse_verbose("not a date 12:00:00");
+    assert!(test.is_err());
+    let attempts = test.unwrap_err();
+    assert!(!attempts.is_empty());
+    assert!(attempts
+        .iter()
+        .any(|(pattern, _)| pattern == "%Y-%m-%dT%H:%M:%S%.f"));
+    assert!(attempts.iter().any(|(pattern, _)| pattern == "Others"));
+    assert!(attempts.iter().all(|(_, error)| !error.is_empty()));
+    let test = parse_verbose("");
+    assert_eq!(
+        test.unwrap_err(),
+        vec![(String::new(), "cannot be empty".to_string())]
+    );
+    // nothing is collected outside of parse_verbose
+    assert!("not a date 12:00:00"
+        .parse::<DateTimeFixedOffset>()
+        .is_err());
+    assert!(crate::trace::collect(|| ()).1.is_empty());
+}
+
+#[test]
+fn test_parse_range() {
+    use crate::{parse_range, ParseError, Parser};
+    for range in [
+        "2023-01-05 to 2023-01-10",
+        "2023-01-05 – 2023-01-10",
+        "2023-01-05..2023-01-10",
+        "Jan 5 2023 until Jan 10 2023",
+        "2023-01-05 - 2023-01-10",
+        "2023-01-05-2023-01-10",
+    ] {
+        let test = parse_range(range);
+        assert!(test.is_ok(), "{}", range);
+        let (start, end) = test.unwrap();
+        assert_eq!(start.0.date_naive().to_string(), "2023-01-05");
+        assert_eq!(end.0.date_naive().to_string(), "2023-01-10");
+    }
+    let range = "2023-01-05T10:00:00-02:00 - 2023-01-05T12:00:00-02:00";
+    let (start, end) = parse_range(range).unwrap();
+    assert_eq!(start.to_string(), "2023-01-05T10:00:00-02:00");
+    assert_eq!(end.to_string(), "2023-01-05T12:00:00-02:00");
+    assert!(parse_range("2023-01-05").is_err());
+    assert!(parse_range("2023-01-05 to later").is_err());
+
+    let reversed = "2023-01-10 to 2023-01-05";
+    assert!(parse_range(reversed).is_ok());
+    let test = Parser::new()
+        .reject_reversed_range(true)
+        .parse_range(reversed);
+    assert_eq!(
+        test.unwrap_err(),
+        ParseError::ReversedRange(reversed.to_string())
+    );
+}
+
+#[test]
+fn test_quarter() {
+    use crate::{parse_with_source, ParseError, ParsedVia, Parser};
+    for (date, expected) in [
+        ("Q1 2023", "2023-01-01"),
+        ("Q2 2023", "2023-04-01"),
+        ("2023 Q3", "2023-07-01"),
+        ("2023-Q4", "2023-10-01"),
+        ("q2 2023", "2023-04-01"),
+    ] {
+        let test = parse_with_source(date);
+        assert!(test.is_ok(), "{}", date);
+        let (dt, via) = test.unwrap();
+        assert_eq!(via, ParsedVia::Quarter);
+        assert_eq!(dt.date_naive().to_string(), expected);
+        assert_eq!(dt.time().to_string(), "00:00:00");
+    }
+    for (date, quarter) in [("Q5 2023", "5"), ("2023-Q0", "0")] {
+        let test = date.parse::<DateTimeFixedOffset>();
+        assert_eq!(
+            test.unwrap_err(),
+            ParseError::OutOfRange {
+                component: "quarter",
+                value: quarter.to_string()
+            }
+        );
+    }
+    let test = Parser::new().reject_year_only(false).parse("2023");
+    assert!(test.is_ok());
+    assert_eq!(test.unwrap().0.date_naive().to_string(), "2023-01-01");
+}
+
+#[test]
+fn test_week() {
+    use crate::{ParseError, ParsedVia, Parser, WeekNumbering};
+    let iso = Parser::new();
+    let us = Parser::new().week_numbering(WeekNumbering::Us);
+    for (date, expected_iso, expected_us) in [
+        ("Week 1 2022", "2022-01-03", "2021-12-27"),
+        ("2022 W1", "2022-01-03", "2021-12-27"),
+        ("2022-W01", "2022-01-03", "2021-12-27"),
+        ("week 5 2023", "2023-01-30", "2023-01-30"),
+    ] {
+        let test = iso.parse_with_source(date);
+        assert!(test.is_ok(), "{}", date);
+        let (dt, via) = test.unwrap();
+        assert_eq!(via, ParsedVia::Week);
+        assert_eq!(dt.0.date_naive().to_string(), expected_iso);
+        let test = us.parse(date);
+        assert!(test.is_ok(), "{}", date);
+        assert_eq!(test.unwrap().0.date_naive().to_string(), expected_us);
+    }
+    // 2022 has 52 ISO weeks but 53 US weeks
+    let out_of_range = |week: &str| ParseError::OutOfRange {
+        component: "week",
+        value: week.to_string(),
+    };
+    assert_eq!(iso.parse("Week 53 2022").unwrap_err(), out_of_range("53"));
+    let test = us.parse("Week 53 2022");
+    assert!(test.is_ok());
+    assert_eq!(test.unwrap().0.date_naive().to_string(), "2022-12-26");
+    assert_eq!(us.parse("Week 54 2022").unwrap_err(), out_of_range("54"));
+    assert_eq!(iso.parse("2022-W00").unwrap_err(), out_of_range("0"));
+}
+
+#[test]
+fn test_dotted_meridiem_spellings() {
+    use crate::Parser;
+    let now = chrono::DateTime::parse_from_rfc3339("2023-01-05T00:00:00+00:00").unwrap();
+    let offset = chrono::FixedOffset::east_opt(0).unwrap();
+    let parser = Parser::new().now(now).local_offset(offset);
+    for (date, expected) in [
+        ("8:23:50 a.m.", "2023-01-05T08:23:50+00:00"),
+        ("8:23:50 P.M.", "2023-01-05T20:23:50+00:00"),
+        ("8:23:50 A.M", "2023-01-05T08:23:50+00:00"),
+        ("8:23p.m.", "2023-01-05T20:23:00+00:00"),
+        ("8:23:50.5 p.m.", "2023-01-05T20:23:50.500+00:00"),
+        ("2023-01-05 8:23:50 p.m.", "2023-01-05T20:23:50+00:00"),
+        ("2023-01-05 8:23 P.M.", "2023-01-05T20:23:00+00:00"),
+        (
+            "Jan 5 2023 8:23:50.25 a.m.",
+            "2023-01-05T08:23:50.250+00:00",
+        ),
+        ("Jan 5 2023 7:27:19 p.m. PDT", "2023-01-05T19:27:19-07:00"),
+    ] {
+        let test = parser.parse(date);
+        assert!(test.is_ok(), "{}", date);
+        assert_eq!(test.unwrap().0.to_rfc3339(), expected);
+    }
+}
+
+#[test]
+fn test_iso_separator() {
+    use crate::normalize;
+    for date in [
+        "2023-01-05T07:27:19.123+02:00",
+        "2023-01-05 07:27:19.123+02:00",
+        "2023-01-05t07:27:19.123 +02:00",
+        "2023-01-05 07:27:19.123 +0200",
+        "2023-01-05 07:27:19.123 GMT+02:00",
+    ] {
+        let test = date.parse::<DateTimeFixedOffset>();
+        assert!(test.is_ok(), "{}", date);
+        assert_eq!(test.unwrap().to_string(), "2023-01-05T07:27:19.123+02:00");
+    }
+    assert_eq!(normalize("2023-01-05 07:27:19"), "2023-01-05T07:27:19");
+    assert_eq!(normalize("1970-1-1 1:1:1 CST"), "1970-1-1T1:1:1 CST");
+    // only a time after an ISO date moves to the `T` form
+    assert_eq!(
+        normalize("2023-01-05 to 2023-01-10"),
+        "2023-01-05 to 2023-01-10"
+    );
+    assert_eq!(normalize("12/13/2000 12:12:12"), "12-13-2000 12:12:12");
+    assert_eq!(normalize("Jan 5 2023 07:27:19"), "Jan 5 2023 07:27:19");
+}
+
+#[test]
+fn test_syslog() {
+    use crate::{ParsedVia, Parser};
+    let now = chrono::DateTime::parse_from_rfc3339("2023-11-05T00:00:00+00:00").unwrap();
+    let offset = chrono::FixedOffset::east_opt(0).unwrap();
+    let parser = Parser::new().now(now).local_offset(offset);
+    for (date, expected) in [
+        ("Oct 11 22:14:15", "2023-10-11T22:14:15+00:00"),
+        ("Oct  1 22:14:15", "2023-10-01T22:14:15+00:00"),
+        ("Oct 01 22:14:15", "2023-10-01T22:14:15+00:00"),
+        ("Oct 11 22:14:15.003", "2023-10-11T22:14:15.003+00:00"),
+    ] {
+        let test = parser.parse_with_source(date);
+        assert!(test.is_ok(), "{}", date);
+        let (dt, via) = test.unwrap();
+        assert_eq!(via, ParsedVia::Syslog);
+        assert_eq!(dt.0.to_rfc3339(), expected);
+    }
+    // RFC 5424 is strict RFC 3339
+    let test = parser.parse_with_source("2003-10-11T22:14:15.003Z");
+    assert!(test.is_ok());
+    let (dt, via) = test.unwrap();
+    assert_eq!(via, ParsedVia::Rfc3339);
+    assert_eq!(dt.0.to_rfc3339(), "2003-10-11T22:14:15.003+00:00");
+}
+
+#[test]
+fn test_clf() {
+    use crate::{normalize, ParsedVia, Parser};
+    // 127.0.0.1 - frank [10/Oct/2000:13:55:36 -0700] "GET /apache_pb.gif HTTP/1.0" 200 2326
+    for date in ["10/Oct/2000:13:55:36 -0700", "[10/Oct/2000:13:55:36 -0700]"] {
+        let test = Parser::new().parse_with_source(date);
+        assert!(test.is_ok(), "{}", date);
+        let (dt, via) = test.unwrap();
+        assert_eq!(via, ParsedVia::Clf);
+        assert_eq!(dt.0.to_rfc3339(), "2000-10-10T13:55:36-07:00");
+    }
+    assert_eq!(
+        normalize("10/Oct/2000:13:55:36 -0700"),
+        "10/Oct/2000:13:55:36 -0700"
+    );
+    let date = "02/Jan/2023:09:15:02 +0000";
+    let test = date.parse::<DateTimeFixedOffset>();
+    assert!(test.is_ok());
+    assert_eq!(test.unwrap().0.to_rfc3339(), "2023-01-02T09:15:02+00:00");
+}
+
+#[test]
+fn test_ambiguous_local_time() {
+    use crate::{Ambiguous, ParseError, Parser, ParserOptions};
+    use chrono::{FixedOffset, NaiveDateTime, TimeZone};
+    // 2023-11-05 01:30 happens twice in New York: in EDT, then again in EST
+    let x = NaiveDateTime::parse_from_str("2023-11-05 01:30:00", "%Y-%m-%d %T").unwrap();
+    let edt = FixedOffset::west_opt(4 * 3600).unwrap();
+    let est = FixedOffset::west_opt(5 * 3600).unwrap();
+    let (a, b) = (
+        est.from_local_datetime(&x).unwrap(),
+        edt.from_local_datetime(&x).unwrap(),
+    );
+    for (policy, expected) in [
+        (Ambiguous::Earliest, Some("2023-11-05T01:30:00-04:00")),
+        (Ambiguous::Latest, Some("2023-11-05T01:30:00-05:00")),
+        (Ambiguous::Reject, None),
+    ] {
+        let options = ParserOptions {
+            ambiguous: policy,
+            ..ParserOptions::default()
+        };
+        let test = options.resolve_ambiguous(&x, a, b);
+        assert_eq!(test.ok().map(|dt| dt.to_rfc3339()).as_deref(), expected);
+    }
+    // through the parser, for the fall-back overlap of the system timezone if it has one
+    let date = ["2023-11-05 01:30:00", "2023-10-29 02:30:00"]
+        .into_iter()
+        .find(|date| {
+            let x = NaiveDateTime::parse_from_str(date, "%Y-%m-%d %T").unwrap();
+            chrono::Local.from_local_datetime(&x).latest()
+                != chrono::Local.from_local_datetime(&x).earliest()
+        });
+    if let Some(date) = date {
+        let earliest = Parser::new().parse(date);
+        let latest = Parser::new().ambiguous(Ambiguous::Latest).parse(date);
+        assert!(earliest.is_ok() && latest.is_ok());
+        assert!(earliest.unwrap().0 < latest.unwrap().0);
+        assert_eq!(
+            Parser::new().ambiguous(Ambiguous::Reject).parse(date),
+            Err(ParseError::AmbiguousLocalTime(date.replace(' ', "T")))
+        );
+    }
+}
+
+#[test]
+fn test_timestamp_units() {
+    for (date, expected) in [
+        ("1672903639s", "2023-01-05T07:27:19+00:00"),
+        ("1672903639123ms", "2023-01-05T07:27:19.123+00:00"),
+        ("1672903639123456us", "2023-01-05T07:27:19.123456+00:00"),
+        (
+            "1672903639123456789ns",
+            "2023-01-05T07:27:19.123456789+00:00",
+        ),
+        ("epoch:1672903639", "2023-01-05T07:27:19+00:00"),
+        ("epoch:1672903639123", "2023-01-05T07:27:19.123+00:00"),
+        // the unit wins over the magnitude
+        ("1672903639123s", "+54982-03-16T15:18:43+00:00"),
+        ("16729ms", "1970-01-01T00:00:16.729+00:00"),
+        ("-1s", "1969-12-31T23:59:59+00:00"),
+    ] {
+        let test = date.parse::<DateTimeFixedOffset>();
+        assert!(test.is_ok(), "{}", date);
+        assert_eq!(test.unwrap().0.to_rfc3339(), expected, "{}", date);
+    }
+    assert!("1e5s".parse::<DateTimeFixedOffset>().is_err());
+    assert!("epoch:".parse::<DateTimeFixedOffset>().is_err());
+}
+
+#[test]
+fn test_max_future_days() {
+    use crate::Parser;
+    let now = chrono::DateTime::parse_from_rfc3339("2024-01-02T09:00:00+00:00").unwrap();
+    let offset = chrono::FixedOffset::east_opt(0).unwrap();
+    let parser = Parser::new().now(now).local_offset(offset);
+    let test = parser.parse("Dec 31 14:00");
+    assert!(test.is_ok());
+    assert_eq!(test.unwrap().0.to_rfc3339(), "2024-12-31T14:00:00+00:00");
+    let parser = parser.max_future_days(30);
+    for (date, expected) in [
+        ("Dec 31 14:00", "2023-12-31T14:00:00+00:00"),
+        ("31 Dec", "2023-12-31T00:00:00+00:00"),
+        ("Dec 31 23:59:59", "2023-12-31T23:59:59+00:00"),
+        ("Jan 5 14:00", "2024-01-05T14:00:00+00:00"),
+        ("Feb 1 00:00", "2024-02-01T00:00:00+00:00"),
+        ("Feb 2 00:00", "2023-02-02T00:00:00+00:00"),
+    ] {
+        let test = parser.parse(date);
+        assert!(test.is_ok(), "{}", date);
+        assert_eq!(test.unwrap().0.to_rfc3339(), expected, "{}", date);
+    }
+}
+
+#[test]
+fn test_expanded_year() {
+    use crate::{ParseError, Parser};
+    let offset = chrono::FixedOffset::east_opt(0).unwrap();
+    let parser = Parser::new().local_offset(offset);
+    for (date, expected) in [
+        ("+012023-01-05", "+12023-01-05T00:00:00+00:00"),
+        ("+012023-01-05T07:27:19Z", "+12023-01-05T07:27:19+00:00"),
+        ("+012023-01-05 07:27:19", "+12023-01-05T07:27:19+00:00"),
+        ("-000044-03-15", "-0044-03-15T00:00:00+00:00"),
+        ("-000044-03-15T12:00:00+01:00", "-0044-03-15T12:00:00+01:00"),
+    ] {
+        let test = parser.parse(date);
+        assert!(test.is_ok(), "{}", date);
+        assert_eq!(test.unwrap().0.to_rfc3339(), expected, "{}", date);
+    }
+    for (date, component, value) in [
+        ("+300000-01-01", "year", "300000"),
+        ("-300000-01-01 10:00", "year", "-300000"),
+        ("+012023-13-05", "month", "13"),
+    ] {
+        assert_eq!(
+            parser.parse(date),
+            Err(ParseError::OutOfRange {
+                component,
+                value: value.to_string()
+            })
+        );
+    }
+    // an input starting with a multibyte character is not an expanded year
+    for date in ["令和5年1月5日", "Été 2023", "É-01-05"] {
+        assert!(parser.parse(date).is_err(), "{}", date);
+        assert!(!crate::has_explicit_timezone(date), "{}", date);
+    }
+    let lenient = parser
+        .coerce_invalid_to_nearest_valid(true)
+        .parse("+012023-02-30");
+    assert!(lenient.is_ok());
+    assert_eq!(
+        lenient.unwrap().0.to_rfc3339(),
+        "+12023-02-28T00:00:00+00:00"
+    );
+}
+
+#[test]
+fn test_strict_ambiguity() {
+    use crate::{DateOrder, ParseError, Parser};
+    let offset = chrono::FixedOffset::east_opt(0).unwrap();
+    let parser = Parser::new().local_offset(offset);
+    let test = parser.parse("01-02-03");
+    assert!(test.is_ok());
+    assert_eq!(test.unwrap().0.to_rfc3339(), "2003-01-02T00:00:00+00:00");
+
+    let strict = parser.strict_ambiguity(true);
+    let test = strict.parse("01-02-03");
+    let candidates = match test {
+        Err(ParseError::Ambiguous { candidates }) => candidates,
+        _ => panic!("expected an ambiguous date, got {:?}", test),
+    };
+    assert_eq!(
+        candidates.iter().map(|x| x.to_string()).collect::<Vec<_>>(),
+        ["2003-01-02T00:00:00+00:00", "2003-02-01T00:00:00+00:00"]
+    );
+    let test = strict
+        .clone()
+        .date_order(DateOrder::Dmy)
+        .parse("01/02/2003");
+    assert!(matches!(test, Err(ParseError::Ambiguous { ref candidates })
+        if candidates[0].to_string() == "2003-02-01T00:00:00+00:00"));
+    // a single reading, or the same date either way, is not ambiguous
+    for (date, expected) in [
+        ("01-13-03", "2003-01-13T00:00:00+00:00"),
+        ("13-01-03", "2003-01-13T00:00:00+00:00"),
+        ("05-05-23", "2023-05-05T00:00:00+00:00"),
+        ("2003-01-02", "2003-01-02T00:00:00+00:00"),
+    ] {
+        let test = strict.parse(date);
+        assert!(test.is_ok(), "{}", date);
+        assert_eq!(test.unwrap().0.to_rfc3339(), expected);
+    }
+}
+
+#[test]
+fn test_parse_date_only() {
+    use crate::{parse_date_only, Parser};
+    for (date, expected) in [
+        ("2023-01-05T23:59:59+05:00", "2023-01-05"),
+        // in UTC, this is still January 4th
+        ("2023-01-05T02:00:00+05:00", "2023-01-05"),
+        // and this is already January 6th
+        ("2023-01-05T22:00:00-05:00", "2023-01-05"),
+        ("Mon, 6 Jul 1970 15:30:00 PDT", "1970-07-06"),
+    ] {
+        let test = parse_date_only(date);
+        assert!(test.is_ok(), "{}", date);
+        assert_eq!(test.unwrap().to_string(), expected);
+    }
+    let utc = chrono::FixedOffset::east_opt(0).unwrap();
+    let test = Parser::new()
+        .output_timezone(utc)
+        .parse_date_only("2023-01-05T02:00:00+05:00");
+    assert!(test.is_ok());
+    assert_eq!(test.unwrap().to_string(), "2023-01-04");
+}
+
+#[test]
+fn test_parse_time_only() {
+    use crate::parse_time_only;
+    for (date, expected) in [
+        ("2023-01-05T07:27:19Z", "07:27:19"),
+        ("2023-01-05T07:27:19.123+05:30", "07:27:19.123"),
+        ("Wed Jul 1 3:33pm PST 1970", "15:33:00"),
+        ("2023-01-05", "00:00:00"),
+        ("January 5 2023", "00:00:00"),
+    ] {
+        let test = parse_time_only(date);
+        assert!(test.is_ok(), "{}", date);
+        assert_eq!(test.unwrap().to_string(), expected);
+    }
+}
+
+#[test]
+fn test_rfc3339_offset_forms() {
+    use crate::{parse_with_source, ParsedVia};
+    for date in [
+        "2023-01-05T07:27:19Z",
+        "2023-01-05T07:27:19+0000",
+        "2023-01-05T07:27:19+00:00",
+        "2023-01-05T12:57:19+05:30",
+        "2023-01-05T12:57:19+0530",
+        "2023-01-05T02:27:19-0500",
+        "2023-01-05 07:27:19.000+0000",
+    ] {
+        let test = parse_with_source(date);
+        assert!(test.is_ok(), "{}", date);
+        let (dt, via) = test.unwrap();
+        assert_eq!(via, ParsedVia::Rfc3339, "{}", date);
+        assert_eq!(dt.timestamp(), 1672903639, "{}", date);
+    }
+}
+
+#[test]
+fn test_fractional_second_precision() {
+    for (fraction, expected) in [
+        ("123", "123000000"),
+        ("123456", "123456000"),
+        ("123456789", "123456789"),
+        ("123456789999", "123456789"),
+    ] {
+        for date in [
+            format!("2023-01-05T07:27:19.{}+02:00", fraction),
+            format!("2023-01-05 07:27:19.{}", fraction),
+            format!("Thursday 05 January 2023 07:27:19.{}+0200", fraction),
+            format!("January 05 2023 07:27:19.{} +0200", fraction),
+            format!("1970-12-25 16:16:16.{} PST", fraction),
+            format!("Thu Jan 5 07:27:19.{} PST 2023", fraction),
+            format!("07:27:19.{}", fraction),
+        ] {
+            let test = date.parse::<DateTimeFixedOffset>();
+            assert!(test.is_ok(), "{}", date);
+            assert_eq!(
+                test.unwrap().0.timestamp_subsec_nanos().to_string(),
+                expected,
+                "{}",
+                date
+            );
+        }
+    }
+}
+
+#[test]
+fn test_parse_in_offset() {
+    use crate::parse_in_offset;
+    use chrono::{FixedOffset, Timelike};
+    let date = "Mon, 6 Jul 1970 15:30:00 PDT";
+    let parsed = date.parse::<DateTimeFixedOffset>().unwrap().0;
+    for (offset, expected) in [
+        (0, "1970-07-06T22:30:00+00:00"),
+        (5 * 3600 + 1800, "1970-07-07T04:00:00+05:30"),
+        (-7 * 3600, "1970-07-06T15:30:00-07:00"),
+    ] {
+        let test = parse_in_offset(date, FixedOffset::east_opt(offset).unwrap());
+        assert!(test.is_ok());
+        let dt = test.unwrap();
+        assert_eq!(dt.to_rfc3339(), expected);
+        assert_eq!(dt, parsed);
+        assert_eq!(dt.timestamp(), parsed.timestamp());
+    }
+    let utc = parse_in_offset(date, FixedOffset::east_opt(0).unwrap()).unwrap();
+    assert_ne!(utc.hour(), parsed.hour());
+}
+
+#[test]
+fn test_ruby_time() {
+    use crate::{parse_with_source, ParsedVia};
+    for (date, expected) in [
+        // Time#to_s
+        ("2023-01-05 07:27:19 +0000", "2023-01-05T07:27:19+00:00"),
+        ("2023-01-05 07:27:19 -0500", "2023-01-05T07:27:19-05:00"),
+        ("2023-01-05 07:27:19 UTC", "2023-01-05T07:27:19+00:00"),
+        // Time#inspect
+        (
+            "2023-01-05 07:27:19.123456789 +0000",
+            "2023-01-05T07:27:19.123456789+00:00",
+        ),
+        (
+            "2023-01-05 07:27:19.5 +0530",
+            "2023-01-05T07:27:19.500+05:30",
+        ),
+    ] {
+        let test = parse_with_source(date);
+        assert!(test.is_ok(), "{}", date);
+        let (dt, via) = test.unwrap();
+        assert_ne!(via, ParsedVia::DatetimeWithoutTz, "{}", date);
+        assert_eq!(dt.to_rfc3339(), expected);
+    }
+    let test = crate::from_datetime_with_tz("2023-01-05T07:27:19.123456789 +0000");
+    assert!(test.is_ok());
+    assert_eq!(
+        test.unwrap().to_rfc3339(),
+        "2023-01-05T07:27:19.123456789+00:00"
+    );
+}
+
+#[test]
+fn test_has_explicit_timezone() {
+    use crate::has_explicit_timezone;
+    for date in [
+        "2023-01-05T07:27:19Z",
+        "2023-01-05T07:27:19+02:00",
+        "2023-01-05T07:27:19.5+0530",
+        "2023-01-05T07:27:19-05",
+        "Mon, 6 Jul 1970 15:30:00 +0200",
+        "2006-01-02 15:04:05 -0700 MST",
+        "Mon, 6 Jul 1970 15:30:00 PDT",
+        "Sun, 06 Nov 1994 08:49:37 GMT",
+        "Feb 14 2022 13:13:55 GMT+0000",
+        "1 Jan 1970 22:00:00 GMT-3",
+        "2023-01-05 07:27:19 UTC",
+        "1970-12-25 16:16:16 Pacific Standard Time",
+        "Wed Jul 1 3:33pm PST 1970",
+        "3:00pm PST",
+        "10/Oct/2000:13:55:36 -0700",
+        "\"2023-01-05T07:27:19Z\"",
+        "[2023-01-05 07:27:19 PST]",
+        "1880-01-01 12:00:00 +00:06:42",
+    ] {
+        assert!(has_explicit_timezone(date), "{}", date);
+    }
+    for date in [
+        "2023-01-05",
+        "2023-01-05 07:27:19",
+        "Jan 5 2023 07:27:19",
+        "12 Feb",
+        "Feb 12 12:00",
+        "07:27:19 pm",
+        "5 January 2023 at 14:30",
+        "1672903639",
+        "+1d 09:00",
+        "",
+    ] {
+        assert!(!has_explicit_timezone(date), "{}", date);
+    }
+}
+
+#[test]
+fn test_unicode_dashes() {
+    use crate::normalize;
+    let offset = chrono::FixedOffset::east_opt(0).unwrap();
+    let parser = crate::Parser::new().local_offset(offset);
+    for (date, expected) in [
+        ("2023\u{2013}01\u{2013}05", "2023-01-05T00:00:00+00:00"),
+        ("2023\u{2010}01\u{2010}05", "2023-01-05T00:00:00+00:00"),
+        ("2023\u{FF0D}01\u{FF0D}05", "2023-01-05T00:00:00+00:00"),
+        ("12\u{2215}13\u{2215}2000", "2000-12-13T00:00:00+00:00"),
+        (
+            "2023\u{2013}01\u{2013}05 07\u{FF1A}27\u{FF1A}19",
+            "2023-01-05T07:27:19+00:00",
+        ),
+        (
+            "2023-01-05T07:27:19\u{2212}05:00",
+            "2023-01-05T07:27:19-05:00",
+        ),
+    ] {
+        let test = parser.parse(date);
+        assert!(test.is_ok(), "{}", date);
+        assert_eq!(test.unwrap().0.to_rfc3339(), expected);
+    }
+    assert_eq!(normalize("2023\u{2013}01\u{2013}05"), "2023-01-05");
+}
+
+#[test]
+fn test_cjk() {
+    use crate::{ParsedVia, Parser};
+    let offset = chrono::FixedOffset::east_opt(8 * 3600).unwrap();
+    let parser = Parser::new().local_offset(offset);
+    for (date, expected) in [
+        ("2023年01月05日", "2023-01-05T00:00:00+08:00"),
+        ("2023年1月5日", "2023-01-05T00:00:00+08:00"),
+        ("2023年1月5日 7时27分", "2023-01-05T07:27:00+08:00"),
+        ("2023年1月5日7時27分19秒", "2023-01-05T07:27:19+08:00"),
+        ("2023年1月5日 7点30", "2023-01-05T07:30:00+08:00"),
+        ("2023年12月31日 23时", "2023-12-31T23:00:00+08:00"),
+    ] {
+        let test = parser.parse_with_source(date);
+        assert!(test.is_ok(), "{}", date);
+        let (dt, via) = test.unwrap();
+        assert_eq!(via, ParsedVia::Cjk);
+        assert_eq!(dt.0.to_rfc3339(), expected);
+    }
+    for date in [
+        "2023年2月30日",
+        "2023年1月",
+        "2023年1月5日 7分",
+        "2023年1月5日 25时",
+    ] {
+        assert!(parser.parse(date).is_err(), "{}", date);
+    }
+}
+
+#[test]
+fn test_gnu_date() {
+    use crate::{parse_with_source, ParsedVia};
+    for (date, expected) in [
+        ("Thu Jan  5 07:27:19 UTC 2023", "2023-01-05T07:27:19+00:00"),
+        ("Thu Jan 12 07:27:19 PST 2023", "2023-01-12T07:27:19-08:00"),
+        (
+            "Thu Jan  5 07:27:19 AM UTC 2023",
+            "2023-01-05T07:27:19+00:00",
+        ),
+        (
+            "Thu Jan  5 07:27:19 PM CET 2023",
+            "2023-01-05T19:27:19+01:00",
+        ),
+    ] {
+        let test = parse_with_source(date);
+        assert!(test.is_ok(), "{}", date);
+        let (dt, via) = test.unwrap();
+        assert_eq!(via, ParsedVia::DatetimeWithTzBeforeYear);
+        assert_eq!(dt.to_rfc3339(), expected);
+    }
+    // date -R
+    let test = "Thu, 05 Jan 2023 07:27:19 +0000".parse::<DateTimeFixedOffset>();
+    assert!(test.is_ok());
+    assert_eq!(test.unwrap().0.to_rfc3339(), "2023-01-05T07:27:19+00:00");
+}
+
+#[test]
+fn test_too_long() {
+    use crate::{ParseError, Parser};
+    let date = "2023-01-05 ".repeat(100_000);
+    assert_eq!(
+        date.parse::<DateTimeFixedOffset>(),
+        Err(ParseError::TooLong {
+            unit: "bytes",
+            max: 256
+        })
+    );
+    let date = "1 ".repeat(20);
+    assert_eq!(
+        date.parse::<DateTimeFixedOffset>(),
+        Err(ParseError::TooLong {
+            unit: "tokens",
+            max: 16
+        })
+    );
+    let date = "Thu Jan  5 07:27:19 AM UTC 2023";
+    assert!(Parser::new().max_tokens(7).parse(date).is_ok());
+    assert!(matches!(
+        Parser::new().max_tokens(6).parse(date),
+        Err(ParseError::TooLong { .. })
+    ));
+    assert!(matches!(
+        Parser::new()
+            .max_input_len(10)
+            .parse("2023-01-05T07:27:19Z"),
+        Err(ParseError::TooLong { .. })
+    ));
+}
+
+#[test]
+fn test_parse_with_formats() {
+    use crate::{parse_with_formats, ParseError, Parser};
+    let date = "05/01/2023";
+    let default = date.parse::<DateTimeFixedOffset>();
+    assert!(default.is_ok());
+    assert_eq!(default.unwrap().0.date_naive().to_string(), "2023-05-01");
+
+    let offset = chrono::FixedOffset::east_opt(3600).unwrap();
+    let parser = Parser::new().local_offset(offset);
+    for (date, format, expected) in [
+        ("05/01/2023", "%d/%m/%Y", "2023-01-05T00:00:00+01:00"),
+        (
+            "05/01/2023 07:27",
+            "%d/%m/%Y %H:%M",
+            "2023-01-05T07:27:00+01:00",
+        ),
+        (
+            "05/01/2023 07:27 -0500",
+            "%d/%m/%Y %H:%M %z",
+            "2023-01-05T07:27:00-05:00",
+        ),
+        ("2023.005", "%Y.%j", "2023-01-05T00:00:00+01:00"),
+    ] {
+        let test = parser.parse_with_formats(date, &["%Y-%m-%d", format]);
+        assert!(test.is_ok(), "{}", date);
+        assert_eq!(test.unwrap().0.to_rfc3339(), expected);
+    }
+    let test = parse_with_formats("2023-01-05T07:27:19Z", &["%d/%m/%Y"]);
+    assert!(matches!(test, Err(ParseError::Unrecognized(_))));
+    assert_eq!(
+        parse_with_formats(" ", &["%d/%m/%Y"]),
+        Err(ParseError::Empty)
+    );
+}
+
+#[test]
+fn test_midnight_gap() {
+    // until 2019 the clocks in Brazil went forward at midnight, so 2018-11-04
+    // started at 01:00. The test re-runs itself in that timezone.
+    const TZ: &str = "America/Sao_Paulo";
+    if std::env::var("TZ").as_deref() != Ok(TZ) {
+        if !std::path::Path::new("/usr/share/zoneinfo")
+            .join(TZ)
+            .exists()
+        {
+            return;
+        }
+        let status = std::process::Command::new(std::env::current_exe().unwrap())
+            .args(["--exact", "tests::test_midnight_gap", "--quiet"])
+            .env("TZ", TZ)
+            .status();
+        assert!(status.is_ok());
+        assert!(status.unwrap().success());
+        return;
+    }
+    for date in ["2018-11-04", "20181104", "11/04/2018"] {
+        let test = date.parse::<DateTimeFixedOffset>();
+        assert!(test.is_ok(), "{}", date);
+        assert_eq!(test.unwrap().0.to_rfc3339(), "2018-11-04T01:00:00-02:00");
+    }
+    let test = crate::Parser::new().parse_with_formats("04.11.2018", &["%d.%m.%Y"]);
+    assert!(test.is_ok());
+    assert_eq!(test.unwrap().0.to_rfc3339(), "2018-11-04T01:00:00-02:00");
+    // a time of day in the gap is still an error unless coerced
+    assert_eq!(
+        "2018-11-04 00:30:00".parse::<DateTimeFixedOffset>(),
+        Err(crate::ParseError::Unrecognized(
+            "2018-11-04 00:30:00 does not exist in the local timezone".to_string()
+        ))
+    );
+}
+
+#[test]
+fn test_assume_year() {
+    use crate::Parser;
+    let now = chrono::DateTime::parse_from_rfc3339("2024-01-02T09:00:00+00:00").unwrap();
+    let offset = chrono::FixedOffset::east_opt(0).unwrap();
+    let parser = Parser::new()
+        .now(now)
+        .local_offset(offset)
+        .max_future_days(30)
+        .assume_year(2016);
+    for (date, expected) in [
+        ("Jan 5 14:00", "2016-01-05T14:00:00+00:00"),
+        ("Feb 29 08:00", "2016-02-29T08:00:00+00:00"),
+        ("15 Jun", "2016-06-15T00:00:00+00:00"),
+        ("Oct 11 22:14:15", "2016-10-11T22:14:15+00:00"),
+        ("Oct  1 22:14:15", "2016-10-01T22:14:15+00:00"),
+        ("Dec 31 14:00", "2016-12-31T14:00:00+00:00"),
+        ("Dec 31 2010 14:00", "2010-12-31T14:00:00+00:00"),
+    ] {
+        let test = parser.parse(date);
+        assert!(test.is_ok(), "{}", date);
+        assert_eq!(test.unwrap().0.to_rfc3339(), expected, "{}", date);
+    }
+    // Feb 29 does not exist in the assumed year
+    assert!(parser
+        .clone()
+        .assume_year(2015)
+        .parse("Feb 29 08:00")
+        .is_err());
+}
+
+#[test]
+fn test_comma_fraction() {
+    let offset = chrono::FixedOffset::east_opt(0).unwrap();
+    let parser = crate::Parser::new().local_offset(offset);
+    for (date, expected) in [
+        (
+            "2023-01-05T07:27:19,123+00:00",
+            "2023-01-05T07:27:19.123+00:00",
+        ),
+        ("2023-01-05T07:27:19,123Z", "2023-01-05T07:27:19.123+00:00"),
+        (
+            "2023-01-05T07:27:19,5-05:00",
+            "2023-01-05T07:27:19.500-05:00",
+        ),
+        (
+            "2023-01-05 07:27:19,123456",
+            "2023-01-05T07:27:19.123456+00:00",
+        ),
+        ("2023-01-05T07:27:19,123", "2023-01-05T07:27:19.123+00:00"),
+        // a textual comma is still dropped
+        ("Thu, 05 Jan 2023 07:27:19 GMT", "2023-01-05T07:27:19+00:00"),
+        ("Feb 12, 2023 14:00:01", "2023-02-12T14:00:01+00:00"),
+    ] {
+        let test = parser.parse(date);
+        assert!(test.is_ok(), "{}", date);
+        assert_eq!(
+            test.unwrap()
+                .0
+                .to_rfc3339_opts(chrono::SecondsFormat::AutoSi, false),
+            expected,
+            "{}",
+            date
+        );
+    }
+}
+
+#[test]
+fn test_parse_or() {
+    use crate::{parse_or, parse_or_else, Parser};
+    let default = chrono::DateTime::parse_from_rfc3339("1970-01-01T00:00:00+00:00").unwrap();
+    let test = parse_or("2023-01-05T07:27:19Z", default);
+    assert_eq!(test.to_rfc3339(), "2023-01-05T07:27:19+00:00");
+    assert_eq!(parse_or("not a date", default), default);
+    assert_eq!(parse_or("", default), default);
+
+    let test = parse_or_else("2023-01-05T07:27:19Z", || unreachable!());
+    assert_eq!(test.to_rfc3339(), "2023-01-05T07:27:19+00:00");
+    assert_eq!(parse_or_else("not a date", || default), default);
+
+    // the fallback also covers inputs rejected by the options
+    let parser = Parser::new().max_input_len(8);
+    assert_eq!(parser.parse_or("2023-01-05T07:27:19Z", default), default);
+    assert_eq!(parser.parse_or_else("2023-01-05", || default), default);
+    assert_eq!(
+        parser
+            .parse_or("20230105", default)
+            .date_naive()
+            .to_string(),
+        "2023-01-05"
+    );
+}
+
+#[test]
+fn test_parenthesised_zone_name() {
+    let offset = chrono::FixedOffset::east_opt(0).unwrap();
+    let parser = crate::Parser::new().local_offset(offset);
+    for (date, expected) in [
+        // the offset wins over the name
+        (
+            "2023-01-05 07:27:19 +0200 (CEST)",
+            "2023-01-05T07:27:19+02:00",
+        ),
+        (
+            "2023-01-05 07:27:19 -0700 (Pacific Daylight Time)",
+            "2023-01-05T07:27:19-07:00",
+        ),
+        (
+            "Thu, 05 Jan 2023 07:27:19 -0800 (PST)",
+            "2023-01-05T07:27:19-08:00",
+        ),
+        (
+            "2023-01-05 07:27:19 CET (CEST)",
+            "2023-01-05T07:27:19+01:00",
+        ),
+        // the name is the zone
+        ("2023-01-05 07:27:19 (CEST)", "2023-01-05T07:27:19+02:00"),
+        (
+            "2023-01-05 07:27:19 (Pacific Daylight Time)",
+            "2023-01-05T07:27:19-07:00",
+        ),
+        (
+            "Thu Jan 05 2023 07:27:19 (PST)",
+            "2023-01-05T07:27:19-08:00",
+        ),
+        // an unknown name is dropped
+        (
+            "2023-01-05 07:27:19 (Somewhere Time)",
+            "2023-01-05T07:27:19+00:00",
+        ),
+    ] {
+        let test = parser.parse(date);
+        assert!(test.is_ok(), "{}", date);
+        assert_eq!(test.unwrap().0.to_rfc3339(), expected, "{}", date);
+    }
+}
+
+#[test]
+fn test_min_max() {
+    use crate::{ParseError, Parser};
+    let min = chrono::DateTime::parse_from_rfc3339("1970-01-01T00:00:00+00:00").unwrap();
+    let max = chrono::DateTime::parse_from_rfc3339("2100-01-01T00:00:00+00:00").unwrap();
+    let parser = Parser::new().min(min).max(max);
+    let test = parser.parse("1672903639");
+    assert!(test.is_ok());
+    assert_eq!(test.unwrap().0.to_rfc3339(), "2023-01-05T07:27:19+00:00");
+    // milliseconds with a unit suffix read as seconds land far in the future
+    assert_eq!(
+        parser.parse("1672903639123s"),
+        Err(ParseError::OutOfRange {
+            component: "date",
+            value: "+54982-03-16T15:18:43+00:00".to_string(),
+        })
+    );
+    assert!(matches!(
+        parser.parse("1969-12-31T23:59:59Z"),
+        Err(ParseError::OutOfRange {
+            component: "date",
+            ..
+        })
+    ));
+    // the bounds are instants, whatever the offset
+    assert!(parser.parse("1970-01-01T00:30:00+01:00").is_err());
+    assert!(parser.parse("1969-12-31T23:30:00-01:00").is_ok());
+    assert!(parser.parse("1970-01-01T00:00:00Z").is_ok());
+    assert!(parser
+        .parse_with_formats("31/12/1969 23:00 +0000", &["%d/%m/%Y %H:%M %z"])
+        .is_err());
+}
+
+#[test]
+fn test_military_zone() {
+    for (date, expected) in [
+        ("Thu, 05 Jan 2023 07:27:19 A", "2023-01-05T07:27:19+01:00"),
+        ("Thu, 05 Jan 2023 07:27:19 Z", "2023-01-05T07:27:19+00:00"),
+        ("Thu, 05 Jan 2023 07:27:19 I", "2023-01-05T07:27:19+09:00"),
+        ("Thu, 05 Jan 2023 07:27:19 K", "2023-01-05T07:27:19+10:00"),
+        ("Thu, 05 Jan 2023 07:27:19 M", "2023-01-05T07:27:19+12:00"),
+        ("Thu, 05 Jan 2023 07:27:19 N", "2023-01-05T07:27:19-01:00"),
+        ("Thu, 05 Jan 2023 07:27:19 Y", "2023-01-05T07:27:19-12:00"),
+        ("5 Jan 2023 07:27 R", "2023-01-05T07:27:00-05:00"),
+        ("2023-01-05 07:27:19 A", "2023-01-05T07:27:19+01:00"),
+    ] {
+        let test = date.parse::<DateTimeFixedOffset>();
+        assert!(test.is_ok(), "{}", date);
+        assert_eq!(test.unwrap().0.to_rfc3339(), expected, "{}", date);
+    }
+    // J is the local time of the observer
+    assert!("Thu, 05 Jan 2023 07:27:19 J"
+        .parse::<DateTimeFixedOffset>()
+        .is_err());
+    assert!(crate::has_explicit_timezone("Thu, 05 Jan 2023 07:27:19 A"));
+}
+
+#[test]
+fn test_offset_origin() {
+    use crate::{parse_with_origin, OffsetOrigin, Parser};
+    for (date, expected) in [
+        ("2023-01-05T07:27:19+02:00", OffsetOrigin::Parsed),
+        ("2023-01-05T07:27:19Z", OffsetOrigin::Parsed),
+        ("Thu, 05 Jan 2023 07:27:19 PST", OffsetOrigin::Parsed),
+        ("2023-01-05 07:27:19 (CEST)", OffsetOrigin::Parsed),
+        ("/Date(1672903639123+0200)/", OffsetOrigin::Parsed),
+        ("\"2023-01-05T07:27:19Z\"", OffsetOrigin::Parsed),
+        ("\"2023-01-05 07:27:19+02:00\"", OffsetOrigin::Parsed),
+        ("[2023-01-05 07:27:19]", OffsetOrigin::Inferred),
+        ("1880-01-01 12:00:00 +00:06:42", OffsetOrigin::Parsed),
+        ("2023-01-05 07:27:19", OffsetOrigin::Inferred),
+        ("2023-01-05", OffsetOrigin::Inferred),
+        ("Feb 12", OffsetOrigin::Inferred),
+        ("09:00:00", OffsetOrigin::Inferred),
+        ("1672903639", OffsetOrigin::DefaultUtc),
+        ("1672903639123ms", OffsetOrigin::DefaultUtc),
+        ("/Date(1672903639123)/", OffsetOrigin::DefaultUtc),
+    ] {
+        let test = parse_with_origin(date);
+        assert!(test.is_ok(), "{}", date);
+        assert_eq!(test.unwrap().1, expected, "{}", date);
+    }
+    // a local offset set in the options is still inferred
+    let offset = chrono::FixedOffset::east_opt(3600).unwrap();
+    let test = Parser::new()
+        .local_offset(offset)
+        .parse_with_origin("2023-01-05 07:27:19");
+    assert!(test.is_ok());
+    let (dt, origin) = test.unwrap();
+    assert_eq!(dt.0.to_rfc3339(), "2023-01-05T07:27:19+01:00");
+    assert_eq!(origin, OffsetOrigin::Inferred);
+}
+
+#[test]
+fn test_compact_time() {
+    use crate::{ParsedVia, Parser};
+    let now = chrono::DateTime::parse_from_rfc3339("2024-01-02T09:00:00+00:00").unwrap();
+    let offset = chrono::FixedOffset::east_opt(3600).unwrap();
+    let parser = Parser::new().now(now).local_offset(offset);
+    for (date, expected) in [
+        ("1430Z", "2024-01-02T14:30:00+00:00"),
+        ("0905Z", "2024-01-02T09:05:00+00:00"),
+        ("1430R", "2024-01-02T14:30:00-05:00"),
+        ("1430 PST", "2024-01-02T14:30:00-08:00"),
+        ("2359 UTC", "2024-01-02T23:59:00+00:00"),
+    ] {
+        let test = parser.parse_with_source(date);
+        assert!(test.is_ok(), "{}", date);
+        let (dt, via) = test.unwrap();
+        assert_eq!(dt.0.to_rfc3339(), expected, "{}", date);
+        assert_eq!(via, ParsedVia::CompactTime, "{}", date);
+    }
+    assert!(parser.parse("2560Z").is_err());
+    assert!(parser.parse("1460Z").is_err());
+    // a bare number stays a unix timestamp unless enabled
+    let test = parser.parse_with_source("1430");
+    assert!(test.is_ok());
+    assert_eq!(test.unwrap().1, ParsedVia::UnixTimestamp);
+    let parser = parser.compact_time(true);
+    let test = parser.parse("1430");
+    assert!(test.is_ok());
+    assert_eq!(test.unwrap().0.to_rfc3339(), "2024-01-02T14:30:00+01:00");
+    assert!(parser.parse("2560").is_err());
+}
+
+#[test]
+fn test_parse_list() {
+    use crate::{parse_list, ParseError, Parser};
+    let test = parse_list("2023-01-05, 2023-01-06T07:27:19Z,Jan 7 2023", ",");
+    assert!(test.is_ok());
+    let dates = test.unwrap();
+    assert_eq!(dates.len(), 3);
+    assert_eq!(dates[1].0.to_rfc3339(), "2023-01-06T07:27:19+00:00");
+    assert_eq!(dates[2].0.date_naive().to_string(), "2023-01-07");
+
+    let test = parse_list(
+        "2023-01-05\nnot a date\n\nThu, 05 Jan 2023 07:27:19 GMT\n",
+        "\n",
+    );
+    assert!(test.is_err());
+    let errors = test.unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].0, 1);
+    assert!(matches!(errors[0].1, ParseError::Unrecognized(_)));
+
+    let test = Parser::new()
+        .skip_blank_lines(false)
+        .parse_list("2023-01-05;;2023-01-06", ";");
+    assert_eq!(test, Err(vec![(1, ParseError::Empty)]));
+}
+
+#[test]
+fn test_is_valid() {
+    use crate::{is_valid, Parser};
+    for date in [
+        "2023-01-05T07:27:19Z",
+        "2023-01-05",
+        "Thu, 05 Jan 2023 07:27:19 GMT",
+        "1672903639",
+        "Feb 12",
+        "09:00:00",
+    ] {
+        assert!(is_valid(date), "{}", date);
+    }
+    for date in [
+        "",
+        " ",
+        "not a date",
+        "2023-02-30",
+        "2023-13-01",
+        "25:00:00",
+    ] {
+        assert!(!is_valid(date), "{}", date);
+    }
+    // options apply
+    let min = chrono::DateTime::parse_from_rfc3339("2000-01-01T00:00:00+00:00").unwrap();
+    assert!(!Parser::new().min(min).is_valid("1999-12-31T23:59:59Z"));
+    assert!(Parser::new().min(min).is_valid("2000-01-01T00:00:00Z"));
+}
+
+#[test]
+fn test_ut_token() {
+    use crate::normalize;
+    assert_eq!(
+        normalize("Thu, 05 Jan 2023 07:27:19 UT"),
+        "Thu 05 Jan 2023 07:27:19 GMT"
+    );
+    assert_eq!(
+        normalize("2023-01-05 07:27:19 UTC"),
+        "2023-01-05T07:27:19 GMT"
+    );
+    assert_eq!(
+        normalize("2023-01-05 07:27:19 UTC+2"),
+        "2023-01-05T07:27:19 GMT+2"
+    );
+    assert_eq!(
+        normalize("Jan 5 2023 07:27:19 OUTPOST"),
+        "Jan 5 2023 07:27:19 OUTPOST"
+    );
+    assert_eq!(normalize("Jan 5 2023 UTOPIA"), "Jan 5 2023 UTOPIA");
+    assert_eq!(normalize("Jan 5 2023 UTCX"), "Jan 5 2023 UTCX");
+    let test = "Thu, 05 Jan 2023 07:27:19 UT".parse::<DateTimeFixedOffset>();
+    assert!(test.is_ok());
+    assert_eq!(test.unwrap().0.to_rfc3339(), "2023-01-05T07:27:19+00:00");
+}
+
+#[test]
+fn test_parse_interval() {
+    use crate::{parse_interval, ParseError, Parser};
+    for (interval, expected_start, expected_end) in [
+        (
+            "2023-01-05T00:00:00Z/P1D",
+            "2023-01-05T00:00:00+00:00",
+            "2023-01-06T00:00:00+00:00",
+        ),
+        (
+            "2023-01-05T00:00:00Z/P1DT2H",
+            "2023-01-05T00:00:00+00:00",
+            "2023-01-06T02:00:00+00:00",
+        ),
+        (
+            "2023-01-05T07:27:19+02:00/PT1H30M0.5S",
+            "2023-01-05T07:27:19+02:00",
+            "2023-01-05T08:57:19.500+02:00",
+        ),
+        (
+            "2023-01-31T00:00:00Z/P1M",
+            "2023-01-31T00:00:00+00:00",
+            "2023-02-28T00:00:00+00:00",
+        ),
+        (
+            "2023-01-05T00:00:00Z/P1Y2W",
+            "2023-01-05T00:00:00+00:00",
+            "2024-01-19T00:00:00+00:00",
+        ),
+        (
+            "2023-01-05T00:00:00Z/2023-01-10T12:00:00Z",
+            "2023-01-05T00:00:00+00:00",
+            "2023-01-10T12:00:00+00:00",
+        ),
+        (
+            "P1D/2023-01-06T00:00:00Z",
+            "2023-01-05T00:00:00+00:00",
+            "2023-01-06T00:00:00+00:00",
+        ),
+    ] {
+        let test = parse_interval(interval);
+        assert!(test.is_ok(), "{}", interval);
+        let (start, end) = test.unwrap();
+        assert_eq!(
+            start
+                .0
+                .to_rfc3339_opts(chrono::SecondsFormat::AutoSi, false),
+            expected_start,
+            "{}",
+            interval
+        );
+        assert_eq!(
+            end.0.to_rfc3339_opts(chrono::SecondsFormat::AutoSi, false),
+            expected_end,
+            "{}",
+            interval
+        );
+    }
+    for interval in [
+        "2023-01-05T00:00:00Z",
+        "2023-01-05T00:00:00Z/P",
+        "2023-01-05T00:00:00Z/PT",
+        "2023-01-05T00:00:00Z/P1H",
+        "P1D/P2D",
+    ] {
+        assert!(parse_interval(interval).is_err(), "{}", interval);
+    }
+    // every component fits on its own, but not their sum
+    for interval in [
+        "2023-01-05T00:00:00Z/P4294967295WT2000000000000H",
+        "2023-01-05T00:00:00Z/PT9223372036854775.9S",
+        "P4294967295Y/2023-01-05T00:00:00Z",
+    ] {
+        assert!(
+            matches!(
+                parse_interval(interval),
+                Err(ParseError::OutOfRange {
+                    component: "duration",
+                    ..
+                })
+            ),
+            "{}",
+            interval
+        );
+    }
+    let test = Parser::new()
+        .reject_reversed_range(true)
+        .parse_interval("2023-01-10T00:00:00Z/2023-01-05T00:00:00Z");
+    assert!(matches!(test, Err(ParseError::ReversedRange(_))));
+}
+
+#[test]
+fn test_timestamp_target_offset() {
+    use crate::Parser;
+    let ist = chrono::FixedOffset::east_opt(5 * 3600 + 1800).unwrap();
+    let utc = "1672903639".parse::<DateTimeFixedOffset>().unwrap().0;
+    assert_eq!(utc.to_rfc3339(), "2023-01-05T07:27:19+00:00");
+    // a timestamp is a UTC instant, whatever the local offset
+    let local = chrono::FixedOffset::east_opt(3600).unwrap();
+    let test = Parser::new().local_offset(local).parse("1672903639");
+    assert!(test.is_ok());
+    assert_eq!(test.unwrap().0.to_rfc3339(), "2023-01-05T07:27:19+00:00");
+
+    let parser = Parser::new().timestamp_target_offset(ist);
+    for date in [
+        "1672903639",
+        "1672903639000",
+        "epoch:1672903639",
+        "/Date(1672903639000)/",
+    ] {
+        let test = parser.parse(date);
+        assert!(test.is_ok(), "{}", date);
+        let dt = test.unwrap().0;
+        assert_eq!(dt.offset(), &ist, "{}", date);
+        assert_eq!(dt, utc, "{}", date);
+    }
+    let test = parser.parse("1672903639");
+    assert_eq!(test.unwrap().0.to_rfc3339(), "2023-01-05T12:57:19+05:30");
+    // inputs with an offset of their own keep it
+    let test = parser.parse("/Date(1672903639000+0200)/");
+    assert_eq!(test.unwrap().0.to_rfc3339(), "2023-01-05T09:27:19+02:00");
+    let test = parser.parse("2023-01-05T07:27:19Z");
+    assert_eq!(test.unwrap().0.to_rfc3339(), "2023-01-05T07:27:19+00:00");
+    // the output timezone takes precedence
+    let test = parser.output_timezone(local).parse("1672903639");
+    assert_eq!(test.unwrap().0.to_rfc3339(), "2023-01-05T08:27:19+01:00");
+}
+
+#[test]
+fn test_month_abbreviation_period() {
+    use crate::Parser;
+    let parser = Parser::new().local_offset(chrono::FixedOffset::east_opt(0).unwrap());
+    for (date, expected) in [
+        ("Jan. 5, 2023", "2023-01-05T00:00:00+00:00"),
+        ("5 Jan. 2023", "2023-01-05T00:00:00+00:00"),
+        ("Sept 5 2023", "2023-09-05T00:00:00+00:00"),
+        ("Sept. 5 2023", "2023-09-05T00:00:00+00:00"),
+        ("5 Sept 2023", "2023-09-05T00:00:00+00:00"),
+        ("Jan. 5 2023 07:27", "2023-01-05T07:27:00+00:00"),
+        (
+            "Thu, Jan. 5, 2023 07:27:19 PST",
+            "2023-01-05T07:27:19-08:00",
+        ),
+    ] {
+        let test = parser.parse(date);
+        assert!(test.is_ok(), "{}", date);
+        assert_eq!(test.unwrap().0.to_rfc3339(), expected, "{}", date);
+    }
+}
+
+#[test]
+fn test_informal_abbreviations() {
+    use crate::Parser;
+    let parser = Parser::new().local_offset(chrono::FixedOffset::east_opt(0).unwrap());
+    for (date, expected) in [
+        ("Sept 5 2023", "2023-09-05T00:00:00+00:00"),
+        ("Tues, 5 Sept 2023", "2023-09-05T00:00:00+00:00"),
+        ("Thur Jan 5 2023", "2023-01-05T00:00:00+00:00"),
+        (
+            "Thurs, Jan 5, 2023 07:27:19 PST",
+            "2023-01-05T07:27:19-08:00",
+        ),
+        ("Thursday, January 5, 2023", "2023-01-05T00:00:00+00:00"),
+    ] {
+        let test = parser.parse(date);
+        assert!(test.is_ok(), "{}", date);
+        assert_eq!(test.unwrap().0.to_rfc3339(), expected, "{}", date);
+    }
+    // the weekday has to match the date
+    assert!(parser.parse("Wed Jan 5 2023").is_err());
+}
+
+#[test]
+fn test_parse_components() {
+    use crate::{ParseError, Parser};
+    let parser = Parser::new().local_offset(chrono::FixedOffset::east_opt(3600).unwrap());
+    for (date, time, tz, expected) in [
+        (
+            "2023-01-05",
+            Some("07:27:19"),
+            Some("PST"),
+            "2023-01-05T07:27:19-08:00",
+        ),
+        (
+            "5 Jan 2023",
+            Some("07:27"),
+            Some("GMT+2"),
+            "2023-01-05T07:27:00+02:00",
+        ),
+        (
+            "Jan 5, 2023",
+            Some("7:27:19 pm"),
+            Some("UTC"),
+            "2023-01-05T19:27:19+00:00",
+        ),
+        (
+            "2023-01-05",
+            Some("07:27:19.123"),
+            Some("Z"),
+            "2023-01-05T07:27:19.123+00:00",
+        ),
+        (
+            "2023-01-05",
+            Some("07:27:19"),
+            Some("-0800"),
+            "2023-01-05T07:27:19-08:00",
+        ),
+        ("2023-01-05", None, Some("PST"), "2023-01-05T00:00:00-08:00"),
+        (
+            "2023-01-05",
+            Some("07:27:19"),
+            None,
+            "2023-01-05T07:27:19+01:00",
+        ),
+        (
+            "2023-01-05",
+            Some(" "),
+            Some(""),
+            "2023-01-05T00:00:00+01:00",
+        ),
+    ] {
+        let test = parser.parse_components(date, time, tz);
+        assert!(test.is_ok(), "{} {:?} {:?}", date, time, tz);
+        assert_eq!(test.unwrap().to_rfc3339(), expected);
+    }
+    let test = parser.parse_components("", Some("07:27:19"), Some("PST"));
+    assert_eq!(test, Err(ParseError::Empty));
+    let test = parser.parse_components("2023-01-05", Some("07:27:19"), Some("XYZ"));
+    assert!(matches!(test, Err(ParseError::Unrecognized(_))));
+}
+
+#[test]
+fn test_timestamp_unit() {
+    for (date, expected) in [
+        // seconds past 2286 stay seconds
+        ("10413792000", "2300-01-01T00:00:00+00:00"),
+        ("32503679999", "2999-12-31T23:59:59+00:00"),
+        // and larger values are the first unit that reads as a plausible year
+        ("32503680000", "1971-01-12T04:48:00+00:00"),
+        ("99999999999", "1973-03-03T09:46:39.999+00:00"),
+        ("1672903639123", "2023-01-05T07:27:19.123+00:00"),
+        ("10413792000000", "2300-01-01T00:00:00+00:00"),
+        ("1672903639123456", "2023-01-05T07:27:19.123456+00:00"),
+        ("1672903639123456789", "2023-01-05T07:27:19.123456789+00:00"),
+        // a tiny value is seconds unless suffixed
+        ("500", "1970-01-01T00:08:20+00:00"),
+        ("500ms", "1970-01-01T00:00:00.500+00:00"),
+    ] {
+        let test = date.parse::<DateTimeFixedOffset>();
+        assert!(test.is_ok(), "{}", date);
+        assert_eq!(test.unwrap().0.to_rfc3339(), expected, "{}", date);
+    }
+}
+
+#[test]
+fn test_parse_best_effort() {
+    use crate::{Parser, Warning};
+    let utc = chrono::FixedOffset::east_opt(0).unwrap();
+    let parser = Parser::new().local_offset(utc).assume_year(2024);
+    let test = parser.parse_best_effort("Feb 12 12:00");
+    assert!(test.is_ok());
+    let (dt, warnings) = test.unwrap();
+    assert_eq!(dt.0.to_rfc3339(), "2024-02-12T12:00:00+00:00");
+    assert_eq!(
+        warnings,
+        vec![Warning::InferredYear(2024), Warning::InferredOffset]
+    );
+
+    let test = parser.parse_best_effort("2023-01-05 07:27:19 CST");
+    assert!(test.is_ok());
+    let (dt, warnings) = test.unwrap();
+    assert_eq!(dt.0.to_rfc3339(), "2023-01-05T07:27:19-06:00");
+    assert_eq!(
+        warnings,
+        vec![Warning::AmbiguousAbbreviation {
+            abbreviation: "CST".to_string(),
+            assumed: chrono::FixedOffset::west_opt(6 * 3600).unwrap(),
+        }]
+    );
+
+    for date in ["2023-01-05T07:27:19.1234567899Z", "1672903639.5"] {
+        let test = parser.parse_best_effort(date);
+        assert!(test.is_ok(), "{}", date);
+        assert_eq!(
+            test.unwrap().1,
+            vec![Warning::TruncatedPrecision],
+            "{}",
+            date
+        );
+    }
+    for date in [
+        "2023-01-05T07:27:19Z",
+        "2023-01-05 07:27:19 PST",
+        "1672903639",
+        "\"2023-01-05T07:27:19Z\"",
+        "[2023-01-05 07:27:19+02:00]",
+    ] {
+        let test = parser.parse_best_effort(date);
+        assert!(test.is_ok(), "{}", date);
+        assert!(test.unwrap().1.is_empty(), "{}", date);
+    }
+}
+
+#[test]
+fn test_ymd_hm_tz() {
+    for (date, expected) in [
+        ("1970-12-25 16:16 PST", "1970-12-25T16:16:00-08:00"),
+        ("1970-12-25T16:16 PST", "1970-12-25T16:16:00-08:00"),
+        (
+            "1970-12-25 16:16 Pacific Standard Time",
+            "1970-12-25T16:16:00-08:00",
+        ),
+        ("1970-12-25 16:16 GMT-3", "1970-12-25T16:16:00-03:00"),
+        // the fraction of a second survives a numeric offset
+        (
+            "July 1 13:19:25.795 +0000 1970",
+            "1970-07-01T13:19:25.795+00:00",
+        ),
+    ] {
+        let test = date.parse::<DateTimeFixedOffset>();
+        assert!(test.is_ok(), "{}", date);
+        assert_eq!(test.unwrap().0.to_rfc3339(), expected, "{}", date);
+    }
+}
+
+#[test]
+fn test_redundant_zone() {
+    use crate::ParseError;
+    for date in [
+        "2023-01-05T07:27:19+00:00Z",
+        "2023-01-05T07:27:19-00:00Z",
+        "2023-01-05T07:27:19+0000 UTC",
+        "2023-01-05 07:27:19 +0000 UTC",
+        "2023-01-05T07:27:19Z UTC",
+        "Thu, 05 Jan 2023 07:27:19 +0000 GMT",
+    ] {
+        let test = date.parse::<DateTimeFixedOffset>();
+        assert!(test.is_ok(), "{}", date);
+        assert_eq!(test.unwrap().0.to_rfc3339(), "2023-01-05T07:27:19+00:00");
+    }
+    for date in [
+        "2023-01-05T07:27:19+02:00Z",
+        "2023-01-05 07:27:19 +0200 UTC",
+    ] {
+        let test = date.parse::<DateTimeFixedOffset>();
+        assert!(
+            matches!(test, Err(ParseError::ConflictingZone(_))),
+            "{}",
+            date
+        );
+    }
+    // the year of a date is not an offset
+    for date in ["12-13-2000 GMT", "12/13/2000 UTC", "12-13-2000Z"] {
+        let test = date.parse::<DateTimeFixedOffset>();
+        assert!(matches!(test, Err(ParseError::Unrecognized(_))), "{}", date);
+    }
+}
+
+#[test]
+fn test_parse_naive() {
+    use crate::Parser;
+    let parser = Parser::new();
+    for date in [
+        "2023-01-05T07:27:19+02:00",
+        "Thu, 05 Jan 2023 07:27:19 PST",
+        "2023-01-05 07:27:19 +0530",
+        "1672903639",
+    ] {
+        let test = parser.parse_naive(date);
+        assert!(test.is_ok(), "{}", date);
+        let zoned = parser.parse(date).unwrap().0;
+        assert_eq!(test.unwrap(), zoned.naive_local(), "{}", date);
+    }
+    for (date, expected) in [
+        ("2023-01-05 07:27:19", "2023-01-05 07:27:19"),
+        ("2023-01-05", "2023-01-05 00:00:00"),
+        // skipped by the clocks going forward in much of Europe
+        ("2023-03-26 02:30:00", "2023-03-26 02:30:00"),
+    ] {
+        let test = parser.parse_naive(date);
+        assert!(test.is_ok(), "{}", date);
+        assert_eq!(test.unwrap().to_string(), expected);
+    }
+    // the output timezone does not apply
+    let ist = chrono::FixedOffset::east_opt(5 * 3600 + 1800).unwrap();
+    let test = parser
+        .output_timezone(ist)
+        .parse_naive("2023-01-05T07:27:19Z");
+    assert_eq!(test.unwrap().to_string(), "2023-01-05 07:27:19");
+
+    // the bounds apply to the wall-clock time of an input without an offset,
+    // whatever the offset of `now` is, and to the instant of one with an offset
+    let now = chrono::DateTime::parse_from_rfc3339("2023-01-05T00:00:00+01:00").unwrap();
+    let max = chrono::DateTime::parse_from_rfc3339("2023-07-01T12:00:00+02:00").unwrap();
+    let parser = Parser::new().now(now).max(max);
+    let test = parser.parse_naive("2023-07-01 11:30:00");
+    assert!(test.is_ok());
+    assert_eq!(test.unwrap().to_string(), "2023-07-01 11:30:00");
+    for date in ["2023-07-01 12:30:00", "2023-07-01T11:30:00+00:00"] {
+        let test = parser.parse_naive(date);
+        assert!(
+            matches!(test, Err(crate::ParseError::OutOfRange { .. })),
+            "{}",
+            date
+        );
+    }
+    let test = Parser::new()
+        .now(now)
+        .min(max)
+        .parse_naive("2023-07-01 11:30:00");
+    assert!(matches!(test, Err(crate::ParseError::OutOfRange { .. })));
+}
+
+#[test]
+fn test_two_digit_year_pivot() {
+    use crate::Parser;
+    for (pivot, date, expected) in [
+        // chrono's pivot by default
+        (1970, "05-06-69", "2069-05-06"),
+        (1970, "05-06-70", "1970-05-06"),
+        (1950, "05-06-49", "2049-05-06"),
+        (1950, "05-06-50", "1950-05-06"),
+        (1940, "05-06-49", "1949-05-06"),
+        (1940, "05-06-39", "2039-05-06"),
+        (1940, "05-06-40", "1940-05-06"),
+        // a 4-digit year is left as is
+        (1940, "05-06-2049", "2049-05-06"),
+    ] {
+        let test = Parser::new().two_digit_year_pivot(pivot).parse_naive(date);
+        assert!(test.is_ok(), "{} {}", pivot, date);
+        assert_eq!(
+            test.unwrap().date().to_string(),
+            expected,
+            "{} {}",
+            pivot,
+            date
+        );
+    }
+    let parser = Parser::new().two_digit_year_pivot(1900);
+    assert!(parser.parse("02-29-00").is_err());
+    let parser = Parser::new().two_digit_year_pivot(1950);
+    assert!(parser.parse("02-29-00").is_ok());
+}
+
+#[test]
+fn test_time_with_offset() {
+    use crate::{ParsedVia, Parser};
+    let now = chrono::DateTime::parse_from_rfc3339("2023-01-05T23:30:00Z").unwrap();
+    let parser = Parser::new().now(now);
+    for (date, expected) in [
+        // the current date in +02:00 is already January 6th
+        ("07:27:19+02:00", "2023-01-06T07:27:19+02:00"),
+        ("07:27:19.5-05:00", "2023-01-05T07:27:19.500-05:00"),
+        ("07:27:19.123+0200", "2023-01-06T07:27:19.123+02:00"),
+        ("07:27+02:00", "2023-01-06T07:27:00+02:00"),
+        ("07:27:19 -05:00", "2023-01-05T07:27:19-05:00"),
+    ] {
+        let test = parser.parse_with_source(date);
+        assert!(test.is_ok(), "{}", date);
+        let (dt, via) = test.unwrap();
+        assert_eq!(dt.0.to_rfc3339(), expected, "{}", date);
+        assert_eq!(via, ParsedVia::TimeWithTz, "{}", date);
+    }
+}
+
+#[test]
+fn test_rfc3339_fast_path() {
+    use crate::{from_canonical_rfc3339, standardize_date, try_pipeline, ParserOptions};
+    let ist = chrono::FixedOffset::east_opt(5 * 3600 + 1800).unwrap();
+    let options = [
+        ParserOptions::default(),
+        ParserOptions {
+            output_timezone: Some(ist),
+            ..Default::default()
+        },
+    ];
+    let dates = [
+        "2023-01-05T07:27:19+02:00",
+        "2023-01-05T07:27:19Z",
+        "2023-01-05T07:27:19z",
+        "2023-01-05T07:27:19.123-05:30",
+        "2023-01-05T07:27:19.123456789+00:00",
+        "2023-01-05T07:27:19.1234567899Z",
+        "2016-12-31T23:59:60.500Z",
+        "1900-01-01T00:00:00-00:00",
+        "0001-01-01T00:00:00Z",
+        "9999-12-31T23:59:59+14:00",
+        " 2023-01-05T07:27:19Z\n",
+    ];
+    for options in &options {
+        for date in dates {
+            let fast = from_canonical_rfc3339(date, options);
+            assert!(fast.is_some(), "{}", date);
+            let slow = try_pipeline(&standardize_date(date), options);
+            assert!(slow.is_ok(), "{}", date);
+            let (fast, (slow, via)) = (fast.unwrap(), slow.unwrap());
+            assert_eq!(fast.to_rfc3339(), slow.to_rfc3339(), "{}", date);
+            assert_eq!(via, crate::ParsedVia::Rfc3339, "{}", date);
+        }
+    }
+    // anything else takes the slow path
+    for date in [
+        "2023-01-05 07:27:19Z",
+        "2023-01-05T07:27:19+0200",
+        "2023-01-05T07:27:19",
+        "2023-02-30T07:27:19Z",
+        "1672903639",
+    ] {
+        assert!(
+            from_canonical_rfc3339(date, &options[0]).is_none(),
+            "{}",
+            date
+        );
+    }
+}
+
+#[test]
+fn test_week_datetime() {
+    use crate::{ParsedVia, Parser};
+    let offset = chrono::FixedOffset::east_opt(3600).unwrap();
+    let parser = Parser::new().local_offset(offset);
+    for (date, expected) in [
+        ("2023-W05-1", "2023-01-30T00:00:00+01:00"),
+        ("2023W051", "2023-01-30T00:00:00+01:00"),
+        ("2023-W05-1T07:27:19Z", "2023-01-30T07:27:19+00:00"),
+        ("2023-W05-1T07:27:19+02:00", "2023-01-30T07:27:19+02:00"),
+        (
+            "2023-W05-7T07:27:19.5-05:00",
+            "2023-02-05T07:27:19.500-05:00",
+        ),
+        ("2023-W05-1T07:27:19", "2023-01-30T07:27:19+01:00"),
+        ("2023-W05-1 07:27", "2023-01-30T07:27:00+01:00"),
+        ("2023W051T072719Z", "2023-01-30T07:27:19+00:00"),
+        ("2023W051T072719+0200", "2023-01-30T07:27:19+02:00"),
+        ("2023W051T072719", "2023-01-30T07:27:19+01:00"),
+    ] {
+        let test = parser.parse_with_source(date);
+        assert!(test.is_ok(), "{}", date);
+        let (dt, via) = test.unwrap();
+        assert_eq!(via, ParsedVia::Week, "{}", date);
+        assert_eq!(dt.0.to_rfc3339(), expected, "{}", date);
+    }
+    // 2023 has 52 ISO weeks
+    assert!(parser.parse("2023-W53-1T07:27:19Z").is_err());
+    assert!(parser.parse("2023-W05-8T07:27:19Z").is_err());
+}
+
+#[test]
+fn test_enclosing_quotes_and_brackets() {
+    use crate::{ParsedVia, Parser};
+    let offset = chrono::FixedOffset::east_opt(3600).unwrap();
+    let parser = Parser::new().local_offset(offset);
+    for (date, expected, expected_via) in [
+        (
+            "\"2023-01-05T07:27:19Z\"",
+            "2023-01-05T07:27:19+00:00",
+            ParsedVia::Rfc3339,
+        ),
+        (
+            " '2023-01-05T07:27:19+02:00' ",
+            "2023-01-05T07:27:19+02:00",
+            ParsedVia::Rfc3339,
+        ),
+        (
+            "[2023-01-05 07:27:19]",
+            "2023-01-05T07:27:19+01:00",
+            ParsedVia::DatetimeWithoutTz,
+        ),
+        (
+            "[ Jan 5 2023 07:27:19 PST ]",
+            "2023-01-05T07:27:19-08:00",
+            ParsedVia::YmdHmsTz,
+        ),
+        (
+            "(1672903639)",
+            "2023-01-05T07:27:19+00:00",
+            ParsedVia::UnixTimestamp,
+        ),
+    ] {
+        let test = parser.parse_with_source(date);
+        assert!(test.is_ok(), "{}", date);
+        let (dt, via) = test.unwrap();
+        assert_eq!(dt.0.to_rfc3339(), expected, "{}", date);
+        assert_eq!(via, expected_via, "{}", date);
+    }
+    // only a single matched pair is stripped
+    for date in [
+        "[2023-01-05 07:27:19",
+        "2023-01-05 07:27:19]",
+        "\"2023-01-05T07:27:19Z",
+        "[2023-01-05 07:27:19)",
+        "[[2023-01-05 07:27:19]]",
+        "\"\"2023-01-05T07:27:19Z\"\"",
+    ] {
+        assert!(parser.parse(date).is_err(), "{}", date);
+    }
+    assert_eq!(parser.parse("\"\"").unwrap_err(), crate::ParseError::Empty);
+}
@@ -0,0 +1,34 @@
+//! Tests for the build without the `std` feature, where there is no system
+//! clock or timezone and local inputs resolve against `ParserOptions.now`
+use crate::{DateTimeFixedOffset, ParseError, Parser};
+use chrono::DateTime;
+
+#[test]
+fn test_offset_inputs_without_std() {
+    let dates = [
+        ("2023-01-05T07:27:19+02:00", "2023-01-05T07:27:19+02:00"),
+        ("Thu, 05 Jan 2023 07:27:19 PST", "2023-01-05T07:27:19-08:00"),
+        ("1672903639", "2023-01-05T07:27:19+00:00"),
+    ];
+    for (date, expected) in dates {
+        let test = date.parse::<DateTimeFixedOffset>();
+        assert!(test.is_ok());
+        assert_eq!(test.unwrap().0.to_rfc3339(), expected);
+    }
+}
+
+#[test]
+fn test_local_inputs_need_a_reference_without_std() {
+    assert!(matches!(
+        "2023-01-05 07:27:19".parse::<DateTimeFixedOffset>(),
+        Err(ParseError::Unrecognized(_))
+    ));
+    let now = DateTime::parse_from_rfc3339("2023-01-05T00:00:00+05:30").unwrap();
+    let parser = Parser::new().now(now);
+    let test = parser.parse("2023-01-05 07:27:19");
+    assert!(test.is_ok());
+    assert_eq!(test.unwrap().0.to_rfc3339(), "2023-01-05T07:27:19+05:30");
+    let test = parser.parse("09:00:00");
+    assert!(test.is_ok());
+    assert_eq!(test.unwrap().0.to_rfc3339(), "2023-01-05T09:00:00+05:30");
+}
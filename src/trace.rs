@@ -0,0 +1,38 @@
+//! Format attempts
+//!
+//! `parse_verbose` reports every format pattern (and parser branch) tried on an
+//! input together with the error it failed with, so that an input which does not
+//! parse can be debugged. The attempts are only collected while it runs.
+use alloc::string::String;
+#[cfg(feature = "std")]
+use alloc::{string::ToString, vec::Vec};
+#[cfg(feature = "std")]
+use core::cell::RefCell;
+
+#[cfg(feature = "std")]
+std::thread_local! {
+    static ATTEMPTS: RefCell<Option<Vec<(String, String)>>> = const { RefCell::new(None) };
+}
+
+/// Records a failed attempt when called from within `collect`
+#[cfg(feature = "std")]
+pub(crate) fn record(pattern: &str, error: impl FnOnce() -> String) {
+    ATTEMPTS.with(|attempts| {
+        if let Some(attempts) = attempts.borrow_mut().as_mut() {
+            attempts.push((pattern.to_string(), error()));
+        }
+    });
+}
+
+/// Without std there is no `parse_verbose` to collect the attempts
+#[cfg(not(feature = "std"))]
+pub(crate) fn record(_pattern: &str, _error: impl FnOnce() -> String) {}
+
+/// Runs `f` and returns its result along with the attempts it recorded
+#[cfg(feature = "std")]
+pub(crate) fn collect<T>(f: impl FnOnce() -> T) -> (T, Vec<(String, String)>) {
+    let previous = ATTEMPTS.with(|attempts| attempts.replace(Some(Vec::new())));
+    let result = f();
+    let attempts = ATTEMPTS.with(|attempts| attempts.replace(previous));
+    (result, attempts.unwrap_or_default())
+}
@@ -0,0 +1,142 @@
+//! Timezone abbreviations
+//!
+//! chrono's RFC 2822 parser only knows the North American zones, so the
+//! common abbreviations from elsewhere, along with the spelled-out names of
+//! the zones, are resolved here to a fixed offset.
+use chrono::FixedOffset;
+
+const HOUR: i32 = 3600;
+
+/// Abbreviation and its offset from UTC in seconds
+const ABBREVIATIONS: &[(&str, i32)] = &[
+    ("UTC", 0),
+    ("GMT", 0),
+    ("UT", 0),
+    ("WET", 0),
+    ("WEST", HOUR),
+    ("BST", HOUR),
+    ("CET", HOUR),
+    ("CEST", 2 * HOUR),
+    ("MET", HOUR),
+    ("MEST", 2 * HOUR),
+    ("WAT", HOUR),
+    ("EET", 2 * HOUR),
+    ("EEST", 3 * HOUR),
+    ("CAT", 2 * HOUR),
+    ("SAST", 2 * HOUR),
+    ("MSK", 3 * HOUR),
+    ("EAT", 3 * HOUR),
+    ("PKT", 5 * HOUR),
+    ("IST", 5 * HOUR + 30 * 60),
+    ("WIB", 7 * HOUR),
+    ("ICT", 7 * HOUR),
+    ("HKT", 8 * HOUR),
+    ("SGT", 8 * HOUR),
+    ("AWST", 8 * HOUR),
+    ("JST", 9 * HOUR),
+    ("KST", 9 * HOUR),
+    ("ACST", 9 * HOUR + 30 * 60),
+    ("ACDT", 10 * HOUR + 30 * 60),
+    ("AEST", 10 * HOUR),
+    ("AEDT", 11 * HOUR),
+    ("NZST", 12 * HOUR),
+    ("NZDT", 13 * HOUR),
+    ("NST", -(3 * HOUR + 30 * 60)),
+    ("NDT", -(2 * HOUR + 30 * 60)),
+    ("BRT", -3 * HOUR),
+    ("ART", -3 * HOUR),
+    ("AST", -4 * HOUR),
+    ("ADT", -3 * HOUR),
+    ("EST", -5 * HOUR),
+    ("EDT", -4 * HOUR),
+    ("CST", -6 * HOUR),
+    ("CDT", -5 * HOUR),
+    ("MST", -7 * HOUR),
+    ("MDT", -6 * HOUR),
+    ("PST", -8 * HOUR),
+    ("PDT", -7 * HOUR),
+    ("AKST", -9 * HOUR),
+    ("AKDT", -8 * HOUR),
+    ("HST", -10 * HOUR),
+];
+
+/// Abbreviations that more than one zone goes by, read as the one of `ABBREVIATIONS`
+const AMBIGUOUS: &[&str] = &[
+    "AST", // also Arabia Standard Time (+03:00)
+    "BST", // also Bangladesh Standard Time (+06:00)
+    "CST", // also China Standard Time (+08:00) and Cuba Standard Time (-05:00)
+    "IST", // also Israel Standard Time (+02:00) and Irish Standard Time (+01:00)
+    "MST", // also Malaysia Standard Time (+08:00)
+];
+
+/// Spelled-out zone name and its abbreviation
+const NAMES: &[(&str, &str)] = &[
+    ("Coordinated Universal Time", "UTC"),
+    ("Greenwich Mean Time", "GMT"),
+    ("Western European Time", "WET"),
+    ("Western European Summer Time", "WEST"),
+    ("British Summer Time", "BST"),
+    ("Central European Time", "CET"),
+    ("Central European Standard Time", "CET"),
+    ("Central European Summer Time", "CEST"),
+    ("Eastern European Time", "EET"),
+    ("Eastern European Standard Time", "EET"),
+    ("Eastern European Summer Time", "EEST"),
+    ("Moscow Standard Time", "MSK"),
+    ("India Standard Time", "IST"),
+    ("Japan Standard Time", "JST"),
+    ("Korea Standard Time", "KST"),
+    ("Australian Eastern Standard Time", "AEST"),
+    ("Australian Eastern Daylight Time", "AEDT"),
+    ("New Zealand Standard Time", "NZST"),
+    ("New Zealand Daylight Time", "NZDT"),
+    ("Atlantic Standard Time", "AST"),
+    ("Atlantic Daylight Time", "ADT"),
+    ("Eastern Standard Time", "EST"),
+    ("Eastern Daylight Time", "EDT"),
+    ("Central Standard Time", "CST"),
+    ("Central Daylight Time", "CDT"),
+    ("Mountain Standard Time", "MST"),
+    ("Mountain Daylight Time", "MDT"),
+    ("Pacific Standard Time", "PST"),
+    ("Pacific Daylight Time", "PDT"),
+    ("Alaska Standard Time", "AKST"),
+    ("Alaska Daylight Time", "AKDT"),
+    ("Hawaii Standard Time", "HST"),
+];
+
+/// Returns the fixed offset of a timezone abbreviation, eg: `CET` to `+01:00`
+pub(crate) fn abbreviation_offset(tz: &str) -> Option<FixedOffset> {
+    ABBREVIATIONS
+        .iter()
+        .find(|(name, _)| *name == tz)
+        .and_then(|(_, secs)| FixedOffset::east_opt(*secs))
+}
+
+/// Checks whether a timezone abbreviation stands for more than one zone, eg: `CST`
+pub(crate) fn is_ambiguous(tz: &str) -> bool {
+    AMBIGUOUS.contains(&tz)
+}
+
+/// Returns the fixed offset of a spelled-out zone name (any case),
+/// eg: `Pacific Standard Time` to `-08:00`
+pub(crate) fn name_offset(tz: &str) -> Option<FixedOffset> {
+    NAMES
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(tz))
+        .and_then(|(_, abbreviation)| abbreviation_offset(abbreviation))
+}
+
+/// Returns the fixed offset of a military (NATO) zone letter, from `A` (+01:00)
+/// to `M` (+12:00) skipping `J`, from `N` (-01:00) to `Y` (-12:00), and `Z` (UTC).
+/// `J` stands for the local time of the observer and has no offset.
+pub(crate) fn military_offset(tz: &str) -> Option<FixedOffset> {
+    let hours = match *tz.as_bytes() {
+        [c @ b'A'..=b'I'] => (c - b'A') as i32 + 1,
+        [c @ b'K'..=b'M'] => (c - b'K') as i32 + 10,
+        [c @ b'N'..=b'Y'] => -((c - b'N') as i32 + 1),
+        [b'Z'] => 0,
+        _ => return None,
+    };
+    FixedOffset::east_opt(hours * HOUR)
+}